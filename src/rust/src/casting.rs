@@ -39,6 +39,7 @@ impl AgoType {
             (AgoType::Float(val), TargetType::Bool) => AgoType::Bool(*val != 0.0),
 
             (AgoType::Bool(val), TargetType::Int) => AgoType::Int(if *val { 1 } else { 0 }),
+            // Intentionally whimsical; use functions::bool_ad_float for 1.0/0.0.
             (AgoType::Bool(val), TargetType::Float) => {
                 AgoType::Float(if *val { 4.2 } else { -3.9 })
             }
@@ -53,10 +54,18 @@ impl AgoType {
                 .map(AgoType::Float)
                 .unwrap_or_else(|_| panic!("Cannot cast string '{}' to Float", val)),
             (AgoType::String(val), TargetType::Bool) => AgoType::Bool(!val.is_empty()),
+            // A String is already a sequence, so it splits into one-character
+            // Strings rather than wrapping like the other scalars below.
+            // Use `collections::singulum` to wrap a String instead.
             (AgoType::String(val), TargetType::StringList) => {
                 AgoType::StringList(val.chars().map(|c| c.to_string()).collect())
             }
 
+            // --- Scalar to Single-Element List Conversions ---
+            (AgoType::Int(val), TargetType::IntList) => AgoType::IntList(vec![*val]),
+            (AgoType::Float(val), TargetType::FloatList) => AgoType::FloatList(vec![*val]),
+            (AgoType::Bool(val), TargetType::BoolList) => AgoType::BoolList(vec![*val]),
+
             // --- List to Primitive Conversions ---
             (AgoType::IntList(val), TargetType::Int) => AgoType::Int(val.len() as i128),
             (AgoType::FloatList(val), TargetType::Int) => AgoType::Int(val.len() as i128),
@@ -64,6 +73,7 @@ impl AgoType {
             (AgoType::StringList(val), TargetType::Int) => AgoType::Int(val.len() as i128),
             (AgoType::ListAny(val), TargetType::Int) => AgoType::Int(val.len() as i128),
 
+            // Every list variant, ListAny included, joins with a single "\n".
             (AgoType::IntList(val), TargetType::String) => {
                 let items: Vec<String> = val.iter().map(|i| i.to_string()).collect();
                 AgoType::String(items.join("\n"))
@@ -108,9 +118,12 @@ impl AgoType {
             }
 
             // --- Struct to String ---
+            // Entries are sorted by key so equal Structs always print the same.
             (AgoType::Struct(val), TargetType::String) => {
+                let mut entries: Vec<(&String, &AgoType)> = val.iter().collect();
+                entries.sort_by(|a, b| a.0.cmp(b.0));
                 let mut parts = Vec::new();
-                for (key, value) in val.iter() {
+                for (key, value) in entries {
                     if let AgoType::String(s) = value.as_type(TargetType::String) {
                         parts.push(format!("{}: {}", key, s));
                     } else {
@@ -144,6 +157,17 @@ impl AgoType {
                 AgoType::IntList(vec)
             }
 
+            // --- Range to FloatList/StringList (via IntList) ---
+            (AgoType::Range(_), TargetType::FloatList | TargetType::StringList) => {
+                self.as_type(TargetType::IntList).as_type(target)
+            }
+
+            // --- Range to Struct (index strings to values, like ListAny case 3) ---
+            (AgoType::Range(_), TargetType::Struct) => self
+                .as_type(TargetType::IntList)
+                .as_type(TargetType::ListAny)
+                .as_type(TargetType::Struct),
+
             // --- List to Range ---
             (AgoType::IntList(val), TargetType::Range) => {
                 let len = val.len() as i128;
@@ -438,7 +462,7 @@ impl AgoType {
                     let mut result: HashMap<String, Vec<i128>> = HashMap::new();
                     for (idx, item) in val.iter().enumerate() {
                         if let AgoType::String(s) = item {
-                            result.entry(s.clone()).or_insert_with(Vec::new).push(idx as i128);
+                            result.entry(s.clone()).or_default().push(idx as i128);
                         }
                     }
                     let struct_map: HashMap<String, AgoType> = result
@@ -492,6 +516,40 @@ impl AgoType {
                 AgoType::StringList(keys)
             }
 
+            // --- Struct to IntList/FloatList (values, sorted by key) ---
+            // Struct -> StringList stays the keys cast above; use
+            // `collections::valores_ordinati` for String values.
+            (AgoType::Struct(val), TargetType::IntList | TargetType::FloatList) => {
+                let mut entries: Vec<(&String, &AgoType)> = val.iter().collect();
+                entries.sort_by(|a, b| a.0.cmp(b.0));
+                match target {
+                    TargetType::IntList => AgoType::IntList(
+                        entries
+                            .into_iter()
+                            .map(|(key, value)| match value {
+                                AgoType::Int(i) => *i,
+                                other => panic!(
+                                    "Cannot cast Struct to IntList: key '{}' holds {:?}",
+                                    key, other
+                                ),
+                            })
+                            .collect(),
+                    ),
+                    _ => AgoType::FloatList(
+                        entries
+                            .into_iter()
+                            .map(|(key, value)| match value {
+                                AgoType::Float(f) => *f,
+                                other => panic!(
+                                    "Cannot cast Struct to FloatList: key '{}' holds {:?}",
+                                    key, other
+                                ),
+                            })
+                            .collect(),
+                    ),
+                }
+            }
+
             // Default error for unsupported conversions
             _ => panic!("Unsupported cast from {:?} to {:?}", self, target),
         }
@@ -1,4 +1,9 @@
-use crate::types::{AgoRange, AgoType};
+use crate::functions::splitmix64;
+use crate::iterators::into_iter;
+use crate::operators::compare;
+use crate::types::{AgoLambda, AgoRange, AgoType, TargetType};
+use std::cmp::Ordering;
+use std::collections::HashMap;
 
 /// Helper to compute slice bounds from a range
 #[inline]
@@ -12,6 +17,100 @@ fn range_bounds(range: &AgoRange, len: usize) -> (usize, usize) {
     (start.min(len), end.min(len))
 }
 
+/// Helper to unpack any list variant into its elements. Panics on non-lists.
+fn list_items(list: &AgoType, func: &str) -> Vec<AgoType> {
+    match list {
+        AgoType::IntList(v) => v.iter().map(|i| AgoType::Int(*i)).collect(),
+        AgoType::FloatList(v) => v.iter().map(|f| AgoType::Float(*f)).collect(),
+        AgoType::BoolList(v) => v.iter().map(|b| AgoType::Bool(*b)).collect(),
+        AgoType::StringList(v) => v.iter().map(|s| AgoType::String(s.clone())).collect(),
+        AgoType::ListAny(v) => v.clone(),
+        other => panic!("Cannot call '{}' on type {:?}", func, other),
+    }
+}
+
+/// Helper to rebuild a list of the same variant as `template` from elements.
+fn collect_like(template: &AgoType, items: Vec<AgoType>) -> AgoType {
+    match template {
+        AgoType::IntList(_) => AgoType::IntList(
+            items
+                .into_iter()
+                .map(|item| match item {
+                    AgoType::Int(i) => i,
+                    other => panic!("Cannot put value of type {:?} in an IntList", other),
+                })
+                .collect(),
+        ),
+        AgoType::FloatList(_) => AgoType::FloatList(
+            items
+                .into_iter()
+                .map(|item| match item {
+                    AgoType::Float(f) => f,
+                    other => panic!("Cannot put value of type {:?} in a FloatList", other),
+                })
+                .collect(),
+        ),
+        AgoType::BoolList(_) => AgoType::BoolList(
+            items
+                .into_iter()
+                .map(|item| match item {
+                    AgoType::Bool(b) => b,
+                    other => panic!("Cannot put value of type {:?} in a BoolList", other),
+                })
+                .collect(),
+        ),
+        AgoType::StringList(_) => AgoType::StringList(
+            items
+                .into_iter()
+                .map(|item| match item {
+                    AgoType::String(s) => s,
+                    other => panic!("Cannot put value of type {:?} in a StringList", other),
+                })
+                .collect(),
+        ),
+        _ => AgoType::ListAny(items),
+    }
+}
+
+/// Helper to pick the empty typed list that holds values like `item`, if any.
+fn typed_list_for(item: &AgoType) -> Option<AgoType> {
+    match item {
+        AgoType::Int(_) => Some(AgoType::IntList(Vec::new())),
+        AgoType::Float(_) => Some(AgoType::FloatList(Vec::new())),
+        AgoType::Bool(_) => Some(AgoType::BoolList(Vec::new())),
+        AgoType::String(_) => Some(AgoType::StringList(Vec::new())),
+        _ => None,
+    }
+}
+
+/// Helper to call a predicate lambda on one element. Panics on non-Bool results.
+fn test_predicate(predicate: &AgoLambda, item: &AgoType, func: &str) -> bool {
+    match predicate(std::slice::from_ref(item)) {
+        AgoType::Bool(b) => b,
+        other => panic!("{} predicate must return a Bool, but got {:?}", func, other),
+    }
+}
+
+/// Helper to convert an Int index to a usize strictly below `limit`.
+/// Panics with the crate's standard out-of-bounds message otherwise.
+fn index_below(index: i128, limit: usize) -> usize {
+    usize::try_from(index)
+        .ok()
+        .filter(|&idx| idx < limit)
+        .unwrap_or_else(|| panic!("Index out of bounds: {}", index))
+}
+
+/// Helper to reject a non-String Struct key, suggesting a cast for scalars.
+fn struct_key_error(key: &AgoType) -> ! {
+    match key {
+        AgoType::Int(_) | AgoType::Float(_) | AgoType::Bool(_) => panic!(
+            "Struct key must be a String, but got {:?} (cast it with as_type(String), or use get_auto/set_auto)",
+            key
+        ),
+        _ => panic!("Struct key must be a String, but got {:?}", key),
+    }
+}
+
 /// Gets a value from an indexable AgoType. Panics on error.
 #[inline]
 pub fn get(iter: &AgoType, n: &AgoType) -> AgoType {
@@ -21,31 +120,31 @@ pub fn get(iter: &AgoType, n: &AgoType) -> AgoType {
             let idx = *index as usize;
             list.get(idx)
                 .map(|val| AgoType::Int(*val))
-                .expect(&format!("Index out of bounds: {}", idx))
+                .unwrap_or_else(|| panic!("Index out of bounds: {}", idx))
         }
         (AgoType::FloatList(list), AgoType::Int(index)) => {
             let idx = *index as usize;
             list.get(idx)
                 .map(|val| AgoType::Float(*val))
-                .expect(&format!("Index out of bounds: {}", idx))
+                .unwrap_or_else(|| panic!("Index out of bounds: {}", idx))
         }
         (AgoType::BoolList(list), AgoType::Int(index)) => {
             let idx = *index as usize;
             list.get(idx)
                 .map(|val| AgoType::Bool(*val))
-                .expect(&format!("Index out of bounds: {}", idx))
+                .unwrap_or_else(|| panic!("Index out of bounds: {}", idx))
         }
         (AgoType::StringList(list), AgoType::Int(index)) => {
             let idx = *index as usize;
             list.get(idx)
                 .map(|val| AgoType::String(val.clone()))
-                .expect(&format!("Index out of bounds: {}", idx))
+                .unwrap_or_else(|| panic!("Index out of bounds: {}", idx))
         }
         (AgoType::ListAny(list), AgoType::Int(index)) => {
             let idx = *index as usize;
             list.get(idx)
-                .map(|val| val.clone())
-                .expect(&format!("Index out of bounds: {}", idx))
+                .cloned()
+                .unwrap_or_else(|| panic!("Index out of bounds: {}", idx))
         }
 
         // --- List Access by Range (sublists) ---
@@ -76,7 +175,7 @@ pub fn get(iter: &AgoType, n: &AgoType) -> AgoType {
             s.chars()
                 .nth(idx)
                 .map(|c| AgoType::String(c.to_string()))
-                .expect(&format!("Index out of bounds: {}", idx))
+                .unwrap_or_else(|| panic!("Index out of bounds: {}", idx))
         }
 
         // --- String Access by Range (substring) ---
@@ -89,11 +188,11 @@ pub fn get(iter: &AgoType, n: &AgoType) -> AgoType {
         // --- Struct Access ---
         (AgoType::Struct(map), AgoType::String(key)) => map
             .get(key)
-            .map(|val| val.clone())
-            .expect(&format!("Key not found: {}", key)),
+            .cloned()
+            .unwrap_or_else(|| panic!("Key not found: {}", key)),
 
         // --- Error Cases ---
-        (AgoType::Struct(_), other) => panic!("Struct key must be a String, but got {:?}", other),
+        (AgoType::Struct(_), other) => struct_key_error(other),
         (
             AgoType::IntList(_)
             | AgoType::FloatList(_)
@@ -198,7 +297,7 @@ pub fn set(iter: &mut AgoType, n: &AgoType, value: &AgoType) {
         }
 
         // --- Error Cases ---
-        (AgoType::Struct(_), other) => panic!("Struct key must be a String, but got {:?}", other),
+        (AgoType::Struct(_), other) => struct_key_error(other),
         (
             AgoType::IntList(_)
             | AgoType::FloatList(_)
@@ -213,6 +312,24 @@ pub fn set(iter: &mut AgoType, n: &AgoType, value: &AgoType) {
     }
 }
 
+/// Like `get`, but a Struct key of any type is first cast to a String, so
+/// dynamic keys such as Ints work. Non-Struct values behave exactly like `get`.
+pub fn get_auto(iter: &AgoType, n: &AgoType) -> AgoType {
+    match iter {
+        AgoType::Struct(_) => get(iter, &n.as_type(TargetType::String)),
+        _ => get(iter, n),
+    }
+}
+
+/// Like `set`, but a Struct key of any type is first cast to a String.
+/// Non-Struct values behave exactly like `set`.
+pub fn set_auto(iter: &mut AgoType, n: &AgoType, value: &AgoType) {
+    match iter {
+        AgoType::Struct(_) => set(iter, &n.as_type(TargetType::String), value),
+        _ => set(iter, n, value),
+    }
+}
+
 /// Inserts a value into an indexable AgoType. Panics on error.
 /// Name ends in -i (returns null/inanis)
 #[inline]
@@ -220,7 +337,7 @@ pub fn inseri(coll: &mut AgoType, key: &AgoType, value: &AgoType) {
     match (coll, key) {
         // --- List Insertion ---
         (AgoType::IntList(list), AgoType::Int(index)) => {
-            let idx = *index as usize;
+            let idx = index_below(*index, list.len() + 1);
             if let AgoType::Int(new_val) = value {
                 list.insert(idx, *new_val);
             } else {
@@ -228,7 +345,7 @@ pub fn inseri(coll: &mut AgoType, key: &AgoType, value: &AgoType) {
             }
         }
         (AgoType::FloatList(list), AgoType::Int(index)) => {
-            let idx = *index as usize;
+            let idx = index_below(*index, list.len() + 1);
             if let AgoType::Float(new_val) = value {
                 list.insert(idx, *new_val);
             } else {
@@ -236,7 +353,7 @@ pub fn inseri(coll: &mut AgoType, key: &AgoType, value: &AgoType) {
             }
         }
         (AgoType::BoolList(list), AgoType::Int(index)) => {
-            let idx = *index as usize;
+            let idx = index_below(*index, list.len() + 1);
             if let AgoType::Bool(new_val) = value {
                 list.insert(idx, *new_val);
             } else {
@@ -244,7 +361,7 @@ pub fn inseri(coll: &mut AgoType, key: &AgoType, value: &AgoType) {
             }
         }
         (AgoType::StringList(list), AgoType::Int(index)) => {
-            let idx = *index as usize;
+            let idx = index_below(*index, list.len() + 1);
             if let AgoType::String(new_val) = value {
                 list.insert(idx, new_val.clone());
             } else {
@@ -252,7 +369,7 @@ pub fn inseri(coll: &mut AgoType, key: &AgoType, value: &AgoType) {
             }
         }
         (AgoType::ListAny(list), AgoType::Int(index)) => {
-            let idx = *index as usize;
+            let idx = index_below(*index, list.len() + 1);
             list.insert(idx, value.clone());
         }
 
@@ -262,7 +379,7 @@ pub fn inseri(coll: &mut AgoType, key: &AgoType, value: &AgoType) {
         }
 
         // --- Error Cases ---
-        (AgoType::Struct(_), other) => panic!("Struct key must be a String, but got {:?}", other),
+        (AgoType::Struct(_), other) => struct_key_error(other),
         (
             AgoType::IntList(_)
             | AgoType::FloatList(_)
@@ -283,33 +400,33 @@ pub fn removium(coll: &mut AgoType, key: &AgoType) -> AgoType {
     match (coll, key) {
         // --- List Removal ---
         (AgoType::IntList(list), AgoType::Int(index)) => {
-            let idx = *index as usize;
+            let idx = index_below(*index, list.len());
             AgoType::Int(list.remove(idx))
         }
         (AgoType::FloatList(list), AgoType::Int(index)) => {
-            let idx = *index as usize;
+            let idx = index_below(*index, list.len());
             AgoType::Float(list.remove(idx))
         }
         (AgoType::BoolList(list), AgoType::Int(index)) => {
-            let idx = *index as usize;
+            let idx = index_below(*index, list.len());
             AgoType::Bool(list.remove(idx))
         }
         (AgoType::StringList(list), AgoType::Int(index)) => {
-            let idx = *index as usize;
+            let idx = index_below(*index, list.len());
             AgoType::String(list.remove(idx))
         }
         (AgoType::ListAny(list), AgoType::Int(index)) => {
-            let idx = *index as usize;
+            let idx = index_below(*index, list.len());
             list.remove(idx)
         }
 
         // --- Struct Removal ---
-        (AgoType::Struct(map), AgoType::String(key)) => {
-            map.remove(key).expect(&format!("Key not found: {}", key))
-        }
+        (AgoType::Struct(map), AgoType::String(key)) => map
+            .remove(key)
+            .unwrap_or_else(|| panic!("Key not found: {}", key)),
 
         // --- Error Cases ---
-        (AgoType::Struct(_), other) => panic!("Struct key must be a String, but got {:?}", other),
+        (AgoType::Struct(_), other) => struct_key_error(other),
         (
             AgoType::IntList(_)
             | AgoType::FloatList(_)
@@ -359,3 +476,917 @@ pub fn validate_list_type(list: &AgoType, expected_elem: &str) -> AgoType {
     }
     list.clone()
 }
+
+/// Validates a ListAny like `validate_list_type`, then casts it to the typed
+/// list for `expected_elem` (Ints are widened when Floats are expected).
+/// Element types without a typed list variant are returned as a ListAny.
+pub fn coerce_list(list: &AgoType, expected_elem: &str) -> AgoType {
+    let checked = validate_list_type(list, expected_elem);
+    let target = match expected_elem {
+        "int" => TargetType::IntList,
+        "float" => TargetType::FloatList,
+        "bool" => TargetType::BoolList,
+        "string" => TargetType::StringList,
+        _ => return checked,
+    };
+    checked.as_type(target)
+}
+
+/// Partitions a list into `[matching, non_matching]` by a predicate lambda.
+/// Both halves keep the original list variant and element order.
+pub fn divide_per(list: &AgoType, predicate: &AgoLambda) -> AgoType {
+    let (matching, rest): (Vec<AgoType>, Vec<AgoType>) = list_items(list, "divide_per")
+        .into_iter()
+        .partition(|item| test_predicate(predicate, item, "divide_per"));
+    AgoType::ListAny(vec![collect_like(list, matching), collect_like(list, rest)])
+}
+
+/// Sorts a list in place by the key `key_fn` extracts from each element.
+/// Keys are ordered with the comparison operator rules; the sort is stable.
+pub fn ordina_per(list: &mut AgoType, key_fn: &AgoLambda) {
+    let mut keyed: Vec<(AgoType, AgoType)> = list_items(list, "ordina_per")
+        .into_iter()
+        .map(|item| (key_fn(std::slice::from_ref(&item)), item))
+        .collect();
+    keyed.sort_by(|(a, _), (b, _)| {
+        compare(a, b).unwrap_or_else(|| panic!("Cannot order keys {:?} and {:?}", a, b))
+    });
+    let sorted = keyed.into_iter().map(|(_, item)| item).collect();
+    *list = collect_like(list, sorted);
+}
+
+/// Pairs each list element with `f`'s result as a ListAny of
+/// `[annotation, element]` pairs, in original order.
+pub fn adnota(list: &AgoType, f: &AgoLambda) -> AgoType {
+    AgoType::ListAny(
+        list_items(list, "adnota")
+            .into_iter()
+            .map(|item| AgoType::ListAny(vec![f(std::slice::from_ref(&item)), item]))
+            .collect(),
+    )
+}
+
+/// Groups list elements into a Struct keyed by the String form of `key_fn`'s
+/// result. Each value is a ListAny of the group's elements, in original order.
+/// Struct keys stringify with sorted entries, so equal Structs group together.
+pub fn congrega(list: &AgoType, key_fn: &AgoLambda) -> AgoType {
+    let mut groups: HashMap<String, Vec<AgoType>> = HashMap::new();
+    for item in list_items(list, "congrega") {
+        let key = key_fn(std::slice::from_ref(&item));
+        let AgoType::String(key) = key.as_type(TargetType::String) else {
+            unreachable!("String cast must return a String");
+        };
+        groups.entry(key).or_default().push(item);
+    }
+    AgoType::Struct(
+        groups
+            .into_iter()
+            .map(|(k, v)| (k, AgoType::ListAny(v)))
+            .collect(),
+    )
+}
+
+/// Counts occurrences of each distinct element, returning a Struct from the
+/// element's String form to its Int count. Elements with the same String form
+/// (e.g. Int 1 and String "1") share a count. Structs stringify with sorted
+/// entries, so equal Structs share a count too.
+pub fn frequentia(list: &AgoType) -> AgoType {
+    let mut counts: HashMap<String, i128> = HashMap::new();
+    for item in list_items(list, "frequentia") {
+        let AgoType::String(key) = item.as_type(TargetType::String) else {
+            unreachable!("String cast must return a String");
+        };
+        *counts.entry(key).or_default() += 1;
+    }
+    AgoType::Struct(
+        counts
+            .into_iter()
+            .map(|(k, n)| (k, AgoType::Int(n)))
+            .collect(),
+    )
+}
+
+/// Helper to find the element whose key is most `wanted` relative to the rest.
+/// Ties keep the first element encountered.
+fn extremum_per(list: &AgoType, key_fn: &AgoLambda, wanted: Ordering, func: &str) -> AgoType {
+    let mut items = list_items(list, func).into_iter();
+    let first = items.next().expect("Cannot reduce an empty list");
+    let mut best_key = key_fn(std::slice::from_ref(&first));
+    let mut best = first;
+    for item in items {
+        let key = key_fn(std::slice::from_ref(&item));
+        let ord = compare(&key, &best_key)
+            .unwrap_or_else(|| panic!("Cannot order keys {:?} and {:?}", key, best_key));
+        if ord == wanted {
+            best_key = key;
+            best = item;
+        }
+    }
+    best
+}
+
+/// Returns the element whose `key_fn` result is smallest.
+pub fn minimum_per(list: &AgoType, key_fn: &AgoLambda) -> AgoType {
+    extremum_per(list, key_fn, Ordering::Less, "minimum_per")
+}
+
+/// Returns the element whose `key_fn` result is largest.
+pub fn maximum_per(list: &AgoType, key_fn: &AgoLambda) -> AgoType {
+    extremum_per(list, key_fn, Ordering::Greater, "maximum_per")
+}
+
+/// Helper to find the most `wanted` element under the stdlib ordering.
+/// Ties keep the first element encountered.
+fn extremum(list: &AgoType, wanted: Ordering, func: &str) -> AgoType {
+    list_items(list, func)
+        .into_iter()
+        .reduce(|best, item| match compare(&item, &best) {
+            Some(ord) if ord == wanted => item,
+            Some(_) => best,
+            None => panic!("Cannot compare {:?} and {:?}", item, best),
+        })
+        .expect("Cannot reduce an empty list")
+}
+
+/// Returns the smallest element of a list, using the same ordering as the
+/// comparison operators.
+pub fn minime(list: &AgoType) -> AgoType {
+    extremum(list, Ordering::Less, "minime")
+}
+
+/// Returns the largest element of a list, using the same ordering as the
+/// comparison operators.
+pub fn maxime(list: &AgoType) -> AgoType {
+    extremum(list, Ordering::Greater, "maxime")
+}
+
+/// Returns `[min, max]` of a list as a ListAny, found in a single pass with
+/// the stdlib ordering. Ties keep the first element encountered.
+pub fn extrema(list: &AgoType) -> AgoType {
+    let mut items = list_items(list, "extrema").into_iter();
+    let first = items.next().expect("Cannot reduce an empty list");
+    let (mut min, mut max) = (first.clone(), first);
+    for item in items {
+        let order = |other: &AgoType| {
+            compare(&item, other)
+                .unwrap_or_else(|| panic!("Cannot compare {:?} and {:?}", item, other))
+        };
+        if order(&min) == Ordering::Less {
+            min = item.clone();
+        } else if order(&max) == Ordering::Greater {
+            max = item.clone();
+        }
+    }
+    AgoType::ListAny(vec![min, max])
+}
+
+/// Checks whether a list is non-decreasing under the stdlib ordering.
+/// Empty and single-element lists are sorted. Panics on incomparable elements.
+pub fn in_ordine(list: &AgoType) -> AgoType {
+    let items = list_items(list, "in_ordine");
+    AgoType::Bool(items.windows(2).all(|pair| {
+        compare(&pair[0], &pair[1])
+            .unwrap_or_else(|| panic!("Cannot compare {:?} and {:?}", pair[0], pair[1]))
+            != Ordering::Greater
+    }))
+}
+
+/// Binary-searches a sorted list for `target` under the stdlib ordering.
+/// Returns the Int index of a matching element (any one, if there are
+/// duplicates), or Null if there is none. Debug builds check adjacent pairs
+/// and panic if the list is not sorted; in release builds unsorted input
+/// gives an unspecified result.
+pub fn quaere_binarium(list: &AgoType, target: &AgoType) -> AgoType {
+    let len = match list {
+        AgoType::IntList(v) => v.len(),
+        AgoType::FloatList(v) => v.len(),
+        AgoType::BoolList(v) => v.len(),
+        AgoType::StringList(v) => v.len(),
+        AgoType::ListAny(v) => v.len(),
+        other => panic!("Cannot call 'quaere_binarium' on type {:?}", other),
+    };
+    if cfg!(debug_assertions) {
+        let mut prev: Option<AgoType> = None;
+        for i in 0..len {
+            let item = get(list, &AgoType::Int(i as i128));
+            if let Some(prev) = &prev {
+                let ord = compare(prev, &item)
+                    .unwrap_or_else(|| panic!("Cannot compare {:?} and {:?}", prev, item));
+                if ord == Ordering::Greater {
+                    panic!("quaere_binarium requires a sorted list, but got {:?}", list);
+                }
+            }
+            prev = Some(item);
+        }
+    }
+    let (mut low, mut high) = (0, len);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        let item = get(list, &AgoType::Int(mid as i128));
+        match compare(&item, target)
+            .unwrap_or_else(|| panic!("Cannot compare {:?} and {:?}", item, target))
+        {
+            Ordering::Less => low = mid + 1,
+            Ordering::Greater => high = mid,
+            Ordering::Equal => return AgoType::Int(mid as i128),
+        }
+    }
+    AgoType::Null
+}
+
+/// Returns true if every element satisfies the predicate (true for empty).
+/// Stops at the first element that fails.
+pub fn omnis(list: &AgoType, predicate: &AgoLambda) -> AgoType {
+    AgoType::Bool(into_iter(list).all(|item| test_predicate(predicate, &item, "omnis")))
+}
+
+/// Returns true if any element satisfies the predicate (false for empty).
+/// Stops at the first element that passes.
+pub fn ullus(list: &AgoType, predicate: &AgoLambda) -> AgoType {
+    AgoType::Bool(into_iter(list).any(|item| test_predicate(predicate, &item, "ullus")))
+}
+
+/// Counts the elements of any iterable for which the predicate returns true.
+pub fn numera_per(list: &AgoType, predicate: &AgoLambda) -> AgoType {
+    let count = into_iter(list)
+        .filter(|item| test_predicate(predicate, item, "numera_per"))
+        .count();
+    AgoType::Int(count as i128)
+}
+
+/// Counts the `true` elements of a BoolList.
+pub fn numera_vera(list: &AgoType) -> AgoType {
+    match list {
+        AgoType::BoolList(v) => AgoType::Int(v.iter().filter(|&&b| b).count() as i128),
+        other => panic!("Cannot call 'numera_vera' on type {:?}", other),
+    }
+}
+
+/// Splits a list into consecutive chunks of `size` elements, returned as a
+/// ListAny of sublists. The last chunk may be shorter.
+pub fn per_partes(list: &AgoType, size: &AgoType) -> AgoType {
+    let size = match size {
+        // A size beyond usize can only mean "one chunk holding everything".
+        AgoType::Int(n) if *n > 0 => usize::try_from(*n).unwrap_or(usize::MAX),
+        other => panic!("Chunk size must be a positive Int, but got {:?}", other),
+    };
+    let chunks = list_items(list, "per_partes")
+        .chunks(size)
+        .map(|chunk| collect_like(list, chunk.to_vec()))
+        .collect();
+    AgoType::ListAny(chunks)
+}
+
+/// Narrows a ListAny to the typed list matching its elements when they all
+/// share one primitive type. Mixed, compound, and empty lists are returned
+/// unchanged, as are lists that are already typed.
+pub fn contrahe(list: &AgoType) -> AgoType {
+    match list {
+        AgoType::ListAny(items) => {
+            let template = items.first().and_then(typed_list_for);
+            match template {
+                Some(template)
+                    if items
+                        .iter()
+                        .all(|item| typed_list_for(item).as_ref() == Some(&template)) =>
+                {
+                    collect_like(&template, items.clone())
+                }
+                _ => list.clone(),
+            }
+        }
+        AgoType::IntList(_)
+        | AgoType::FloatList(_)
+        | AgoType::BoolList(_)
+        | AgoType::StringList(_) => list.clone(),
+        other => panic!("Cannot call 'contrahe' on type {:?}", other),
+    }
+}
+
+/// Helper to read the first (or last) element of a list or character of a
+/// String without copying the rest. Returns None when it is empty.
+fn sequence_end(seq: &AgoType, last: bool, func: &str) -> Option<AgoType> {
+    fn end<T>(v: &[T], last: bool) -> Option<&T> {
+        if last {
+            v.last()
+        } else {
+            v.first()
+        }
+    }
+    match seq {
+        AgoType::String(s) => {
+            let c = if last {
+                s.chars().next_back()
+            } else {
+                s.chars().next()
+            };
+            c.map(|c| AgoType::String(c.to_string()))
+        }
+        AgoType::IntList(v) => end(v, last).map(|i| AgoType::Int(*i)),
+        AgoType::FloatList(v) => end(v, last).map(|f| AgoType::Float(*f)),
+        AgoType::BoolList(v) => end(v, last).map(|b| AgoType::Bool(*b)),
+        AgoType::StringList(v) => end(v, last).map(|s| AgoType::String(s.clone())),
+        AgoType::ListAny(v) => end(v, last).cloned(),
+        other => panic!("Cannot call '{}' on type {:?}", func, other),
+    }
+}
+
+/// Helper to panic with the right noun for an empty list or String.
+fn empty_sequence(seq: &AgoType, func: &str) -> ! {
+    match seq {
+        AgoType::String(_) => panic!("Cannot take '{}' of an empty string", func),
+        _ => panic!("Cannot take '{}' of an empty list", func),
+    }
+}
+
+/// Returns the first element of a list or the first character of a String.
+pub fn caput(seq: &AgoType) -> AgoType {
+    sequence_end(seq, false, "caput").unwrap_or_else(|| empty_sequence(seq, "caput"))
+}
+
+/// Returns all but the first element of a list (or character of a String).
+/// The tail of an empty list or String is empty.
+pub fn cauda(seq: &AgoType) -> AgoType {
+    match seq {
+        AgoType::String(s) => AgoType::String(s.chars().skip(1).collect()),
+        _ => collect_like(seq, list_items(seq, "cauda").into_iter().skip(1).collect()),
+    }
+}
+
+/// Returns the last element of a list or the last character of a String.
+pub fn ultimum(seq: &AgoType) -> AgoType {
+    sequence_end(seq, true, "ultimum").unwrap_or_else(|| empty_sequence(seq, "ultimum"))
+}
+
+/// Builds a list holding `value` repeated `count` times. Primitive values give
+/// the matching typed list (Int -> IntList, etc.), compound values a ListAny.
+pub fn repete(value: &AgoType, count: &AgoType) -> AgoType {
+    let count = match count {
+        AgoType::Int(n) if *n >= 0 => usize::try_from(*n)
+            .unwrap_or_else(|_| panic!("Repeat count {} is too large for a list", n)),
+        other => panic!(
+            "Repeat count must be a non-negative Int, but got {:?}",
+            other
+        ),
+    };
+    let template = typed_list_for(value).unwrap_or(AgoType::ListAny(Vec::new()));
+    collect_like(&template, vec![value.clone(); count])
+}
+
+/// Wraps a value in a single-element list of the matching type. Unlike the
+/// `String -> StringList` cast, which splits into characters, a String gives
+/// a one-element StringList.
+pub fn singulum(value: &AgoType) -> AgoType {
+    repete(value, &AgoType::Int(1))
+}
+
+/// Builds an arithmetic progression of `count` elements from `start` by
+/// `step`. Gives an IntList for Int arguments, or a FloatList if `start` or
+/// `step` is a Float.
+pub fn sequentia(start: &AgoType, count: &AgoType, step: &AgoType) -> AgoType {
+    let count = match count {
+        AgoType::Int(n) if *n >= 0 => *n,
+        other => panic!(
+            "Sequence count must be a non-negative Int, but got {:?}",
+            other
+        ),
+    };
+    match (start, step) {
+        (AgoType::Int(a), AgoType::Int(d)) => AgoType::IntList(
+            (0..count)
+                .map(|i| {
+                    i.checked_mul(*d)
+                        .and_then(|offset| a.checked_add(offset))
+                        .unwrap_or_else(|| panic!("Integer overflow in sequentia at element {}", i))
+                })
+                .collect(),
+        ),
+        (AgoType::Int(_) | AgoType::Float(_), AgoType::Int(_) | AgoType::Float(_)) => {
+            let (a, d) = match (
+                start.as_type(TargetType::Float),
+                step.as_type(TargetType::Float),
+            ) {
+                (AgoType::Float(a), AgoType::Float(d)) => (a, d),
+                _ => unreachable!(),
+            };
+            AgoType::FloatList((0..count).map(|i| a + i as f64 * d).collect())
+        }
+        _ => panic!(
+            "sequentia expects a numeric start and step, but got {:?} and {:?}",
+            start, step
+        ),
+    }
+}
+
+/// Prefix scan: folds `f(&[accumulator, element])` over a list and returns
+/// every accumulator state as a ListAny, starting with `initial`.
+pub fn cumula(list: &AgoType, initial: &AgoType, f: &AgoLambda) -> AgoType {
+    let mut states = vec![initial.clone()];
+    for item in list_items(list, "cumula") {
+        let next = f(&[states[states.len() - 1].clone(), item]);
+        states.push(next);
+    }
+    AgoType::ListAny(states)
+}
+
+/// Maximum number of values `itera_dum` will generate before giving up.
+const ITERA_DUM_LIMIT: usize = 1_000_000;
+
+/// Unfolds a ListAny from `seed`: while `predicate` holds for the current
+/// value it is collected and `step` produces the next one. Panics after
+/// 1,000,000 values to guard against a predicate that never fails.
+pub fn itera_dum(seed: &AgoType, step: &AgoLambda, predicate: &AgoLambda) -> AgoType {
+    let mut values = Vec::new();
+    let mut current = seed.clone();
+    while test_predicate(predicate, &current, "itera_dum") {
+        if values.len() == ITERA_DUM_LIMIT {
+            panic!("itera_dum exceeded {} iterations", ITERA_DUM_LIMIT);
+        }
+        let next = step(std::slice::from_ref(&current));
+        values.push(current);
+        current = next;
+    }
+    AgoType::ListAny(values)
+}
+
+/// Helper to swap two elements of a slice, with the crate's bounds message.
+fn swap_checked<T>(v: &mut [T], i: i128, j: i128) {
+    let (i, j) = (index_below(i, v.len()), index_below(j, v.len()));
+    v.swap(i, j);
+}
+
+/// Swaps the elements at indices `i` and `j` of any list in place.
+pub fn permuta(list: &mut AgoType, i: &AgoType, j: &AgoType) {
+    let (i, j) = match (i, j) {
+        (AgoType::Int(i), AgoType::Int(j)) => (*i, *j),
+        _ => panic!("Indices must be Ints, but got {:?} and {:?}", i, j),
+    };
+    match list {
+        AgoType::IntList(v) => swap_checked(v, i, j),
+        AgoType::FloatList(v) => swap_checked(v, i, j),
+        AgoType::BoolList(v) => swap_checked(v, i, j),
+        AgoType::StringList(v) => swap_checked(v, i, j),
+        AgoType::ListAny(v) => swap_checked(v, i, j),
+        other => panic!("Cannot call 'permuta' on type {:?}", other),
+    }
+}
+
+/// Returns a shuffled copy of a list. The permutation is fully determined by
+/// the Int `seed`, so the same seed always gives the same order.
+pub fn misce(list: &AgoType, seed: &AgoType) -> AgoType {
+    let mut state = match seed {
+        AgoType::Int(n) => *n as u64,
+        other => panic!("Shuffle seed must be an Int, but got {:?}", other),
+    };
+    let mut items = list_items(list, "misce");
+    // Fisher-Yates
+    for i in (1..items.len()).rev() {
+        let j = (splitmix64(&mut state) % (i as u64 + 1)) as usize;
+        items.swap(i, j);
+    }
+    collect_like(list, items)
+}
+
+/// Returns a copy of a list rotated left by `n` positions; a negative `n`
+/// rotates right. `n` wraps modulo the length.
+pub fn rotatio(list: &AgoType, n: &AgoType) -> AgoType {
+    let AgoType::Int(n) = n else {
+        panic!("Rotation amount must be an Int, but got {:?}", n);
+    };
+    let mut items = list_items(list, "rotatio");
+    if !items.is_empty() {
+        let shift = n.rem_euclid(items.len() as i128) as usize;
+        items.rotate_left(shift);
+    }
+    collect_like(list, items)
+}
+
+/// Interleaves two lists as `[a1, b1, a2, b2, ...]`, then appends the rest of
+/// the longer one. The result keeps the input variant when both lists share
+/// it, and is a ListAny otherwise.
+pub fn intercala(left: &AgoType, right: &AgoType) -> AgoType {
+    let mut lefts = list_items(left, "intercala").into_iter();
+    let mut rights = list_items(right, "intercala").into_iter();
+    let mut items = Vec::new();
+    loop {
+        match (lefts.next(), rights.next()) {
+            (None, None) => break,
+            (a, b) => items.extend(a.into_iter().chain(b)),
+        }
+    }
+    if std::mem::discriminant(left) == std::mem::discriminant(right) {
+        collect_like(left, items)
+    } else {
+        AgoType::ListAny(items)
+    }
+}
+
+/// Transposes a ListAny of equal-length row lists into a ListAny of columns.
+/// Columns use the rows' list variant when all rows share one, and are
+/// ListAny otherwise. Panics on ragged rows.
+pub fn transpone(matrix: &AgoType) -> AgoType {
+    let AgoType::ListAny(rows) = matrix else {
+        panic!("transpone expects a ListAny of rows, but got {:?}", matrix);
+    };
+    let Some(first) = rows.first() else {
+        return AgoType::ListAny(vec![]);
+    };
+    let grid: Vec<Vec<AgoType>> = rows
+        .iter()
+        .map(|row| list_items(row, "transpone"))
+        .collect();
+    let width = grid[0].len();
+    for (i, row) in grid.iter().enumerate() {
+        if row.len() != width {
+            panic!(
+                "transpone needs rows of equal length, but row {} has {} elements and row 0 has {}",
+                i,
+                row.len(),
+                width
+            );
+        }
+    }
+    let template = if rows
+        .iter()
+        .all(|row| std::mem::discriminant(row) == std::mem::discriminant(first))
+    {
+        first.clone()
+    } else {
+        AgoType::ListAny(vec![])
+    };
+    let columns = (0..width)
+        .map(|col| collect_like(&template, grid.iter().map(|row| row[col].clone()).collect()))
+        .collect();
+    AgoType::ListAny(columns)
+}
+
+/// Run-length encodes a list or String into a ListAny of `[value, count]`
+/// pairs, merging adjacent equal elements. Strings are encoded per character.
+pub fn plica(list: &AgoType) -> AgoType {
+    let items = match list {
+        AgoType::String(_) => into_iter(list).collect(),
+        _ => list_items(list, "plica"),
+    };
+    let mut runs: Vec<(AgoType, i128)> = Vec::new();
+    for item in items {
+        match runs.last_mut() {
+            Some((value, count)) if *value == item => *count += 1,
+            _ => runs.push((item, 1)),
+        }
+    }
+    AgoType::ListAny(
+        runs.into_iter()
+            .map(|(value, count)| AgoType::ListAny(vec![value, AgoType::Int(count)]))
+            .collect(),
+    )
+}
+
+/// Decodes `[value, count]` pairs produced by `plica` into `target`, which
+/// may be any list type or String. The pairs do not record the source
+/// variant, so pass it back in: `explica(&plica(&x), x.target_type()) == x`.
+pub fn explica(runs: &AgoType, target: TargetType) -> AgoType {
+    let mut items = Vec::new();
+    for run in list_items(runs, "explica") {
+        match &run {
+            AgoType::ListAny(pair) if pair.len() == 2 => match &pair[1] {
+                AgoType::Int(count) if *count >= 0 => {
+                    for _ in 0..*count {
+                        items.push(pair[0].clone());
+                    }
+                }
+                _ => panic!(
+                    "explica run count must be a non-negative Int, but got {:?}",
+                    pair[1]
+                ),
+            },
+            other => panic!("explica expects [value, count] pairs, but got {:?}", other),
+        }
+    }
+    let template = match target {
+        TargetType::String => {
+            return AgoType::String(
+                items
+                    .into_iter()
+                    .map(|item| match item {
+                        AgoType::String(s) => s,
+                        other => panic!("Cannot put value of type {:?} in a String", other),
+                    })
+                    .collect(),
+            )
+        }
+        TargetType::IntList => AgoType::IntList(Vec::new()),
+        TargetType::FloatList => AgoType::FloatList(Vec::new()),
+        TargetType::BoolList => AgoType::BoolList(Vec::new()),
+        TargetType::StringList => AgoType::StringList(Vec::new()),
+        TargetType::ListAny => AgoType::ListAny(Vec::new()),
+        other => panic!("explica cannot decode into {:?}", other),
+    };
+    collect_like(&template, items)
+}
+
+/// Returns a copy of the `base` Struct with every key of `updates` set to its
+/// new value. Neither input is modified; this is what `{...base, key: v}`
+/// lowers to.
+pub fn cum(base: &AgoType, updates: &AgoType) -> AgoType {
+    match (base, updates) {
+        (AgoType::Struct(base), AgoType::Struct(updates)) => {
+            let mut merged = base.clone();
+            merged.extend(updates.iter().map(|(k, v)| (k.clone(), v.clone())));
+            AgoType::Struct(merged)
+        }
+        _ => panic!("Cannot call 'cum' on {:?} and {:?}", base, updates),
+    }
+}
+
+/// Returns a copy of a Struct without the keys in the given StringList.
+/// Keys that are not present are ignored.
+pub fn omitte_claves(s: &AgoType, keys: &AgoType) -> AgoType {
+    match (s, keys) {
+        (AgoType::Struct(map), AgoType::StringList(keys)) => {
+            let mut kept = map.clone();
+            for key in keys {
+                kept.remove(key);
+            }
+            AgoType::Struct(kept)
+        }
+        _ => panic!("Cannot call 'omitte_claves' on {:?} and {:?}", s, keys),
+    }
+}
+
+/// Returns a new Struct with only the entries for which
+/// `predicate(&[key, value])` returns true. The key is passed as a String.
+pub fn filtra_claves(s: &AgoType, predicate: &AgoLambda) -> AgoType {
+    let AgoType::Struct(map) = s else {
+        panic!("Cannot call 'filtra_claves' on type {:?}", s);
+    };
+    let kept = map
+        .iter()
+        .filter(|(key, value)| {
+            match predicate(&[AgoType::String((*key).clone()), (*value).clone()]) {
+                AgoType::Bool(b) => b,
+                other => panic!(
+                    "filtra_claves predicate must return a Bool, but got {:?}",
+                    other
+                ),
+            }
+        })
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect();
+    AgoType::Struct(kept)
+}
+
+/// Returns a new Struct with `f` applied to every value. Keys are unchanged.
+pub fn transforma_valores(s: &AgoType, f: &AgoLambda) -> AgoType {
+    match s {
+        AgoType::Struct(map) => AgoType::Struct(
+            map.iter()
+                .map(|(key, value)| (key.clone(), f(std::slice::from_ref(value))))
+                .collect(),
+        ),
+        other => panic!("Cannot call 'transforma_valores' on type {:?}", other),
+    }
+}
+
+/// Deep-merges two Structs into a new one. Where both sides hold a Struct
+/// under the same key they are merged recursively; otherwise the right
+/// value wins.
+pub fn fusio_profunda(left: &AgoType, right: &AgoType) -> AgoType {
+    match (left, right) {
+        (AgoType::Struct(l), AgoType::Struct(r)) => {
+            let mut merged = l.clone();
+            for (key, value) in r {
+                let combined = match (merged.get(key), value) {
+                    (Some(old @ AgoType::Struct(_)), AgoType::Struct(_)) => {
+                        fusio_profunda(old, value)
+                    }
+                    _ => value.clone(),
+                };
+                merged.insert(key.clone(), combined);
+            }
+            AgoType::Struct(merged)
+        }
+        _ => panic!("Cannot call 'fusio_profunda' on {:?} and {:?}", left, right),
+    }
+}
+
+/// Helper to read a key path as a list of steps (String keys or Int indices).
+fn path_steps(path: &AgoType, func: &str) -> Vec<AgoType> {
+    match path {
+        AgoType::StringList(keys) => keys.iter().cloned().map(AgoType::String).collect(),
+        AgoType::ListAny(steps) => steps.clone(),
+        other => panic!(
+            "{} path must be a StringList or ListAny, but got {:?}",
+            func, other
+        ),
+    }
+}
+
+/// Helper to render a path prefix for error messages, e.g. `config.items.0`.
+fn path_display(steps: &[AgoType]) -> String {
+    steps
+        .iter()
+        .map(|step| match step {
+            AgoType::String(key) => key.clone(),
+            AgoType::Int(i) => i.to_string(),
+            other => format!("{:?}", other),
+        })
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// Helper to step into a Struct by key or a ListAny by index.
+fn path_child<'a>(node: &'a AgoType, step: &AgoType) -> Option<&'a AgoType> {
+    match (node, step) {
+        (AgoType::Struct(map), AgoType::String(key)) => map.get(key),
+        (AgoType::ListAny(list), AgoType::Int(i)) => {
+            usize::try_from(*i).ok().and_then(|i| list.get(i))
+        }
+        _ => None,
+    }
+}
+
+/// Mutable counterpart of `path_child`.
+fn path_child_mut<'a>(node: &'a mut AgoType, step: &AgoType) -> Option<&'a mut AgoType> {
+    match (node, step) {
+        (AgoType::Struct(map), AgoType::String(key)) => map.get_mut(key),
+        (AgoType::ListAny(list), AgoType::Int(i)) => {
+            usize::try_from(*i).ok().and_then(|i| list.get_mut(i))
+        }
+        _ => None,
+    }
+}
+
+/// Helper to read an element of a typed list, which can only end a path.
+fn path_leaf(node: &AgoType, step: &AgoType) -> Option<AgoType> {
+    let AgoType::Int(i) = step else {
+        return None;
+    };
+    let idx = usize::try_from(*i).ok()?;
+    match node {
+        AgoType::IntList(v) => v.get(idx).map(|x| AgoType::Int(*x)),
+        AgoType::FloatList(v) => v.get(idx).map(|x| AgoType::Float(*x)),
+        AgoType::BoolList(v) => v.get(idx).map(|x| AgoType::Bool(*x)),
+        AgoType::StringList(v) => v.get(idx).map(|x| AgoType::String(x.clone())),
+        _ => None,
+    }
+}
+
+/// Reads a value nested inside Structs and lists. `path` is a StringList of
+/// keys or a ListAny of String keys and Int indices. Panics naming the path
+/// prefix that could not be resolved.
+pub fn profunda_get(root: &AgoType, path: &AgoType) -> AgoType {
+    let steps = path_steps(path, "profunda_get");
+    let mut current = root;
+    for (i, step) in steps.iter().enumerate() {
+        match path_child(current, step) {
+            Some(next) => current = next,
+            None => {
+                if i + 1 == steps.len() {
+                    if let Some(leaf) = path_leaf(current, step) {
+                        return leaf;
+                    }
+                }
+                panic!("Path not found: {}", path_display(&steps[..=i]));
+            }
+        }
+    }
+    current.clone()
+}
+
+/// Sets a value nested inside Structs and lists, following the same paths as
+/// `profunda_get`. Every step but the last must already exist; the last step
+/// behaves like `set`, so it may add a new Struct key.
+pub fn profunda_set(root: &mut AgoType, path: &AgoType, value: &AgoType) {
+    let steps = path_steps(path, "profunda_set");
+    let Some((last, parents)) = steps.split_last() else {
+        panic!("profunda_set path must not be empty");
+    };
+    let mut current = root;
+    for (i, step) in parents.iter().enumerate() {
+        current = path_child_mut(current, step)
+            .unwrap_or_else(|| panic!("Path not found: {}", path_display(&steps[..=i])));
+    }
+    set(current, last, value);
+}
+
+/// Builds a Struct by pairing a StringList of keys with a list of values.
+/// The lengths must match; a repeated key keeps its last value.
+pub fn tabula(keys: &AgoType, values: &AgoType) -> AgoType {
+    let AgoType::StringList(keys) = keys else {
+        panic!("tabula keys must be a StringList, but got {:?}", keys);
+    };
+    let values = list_items(values, "tabula");
+    if keys.len() != values.len() {
+        panic!(
+            "tabula needs as many values as keys, but got {} keys and {} values",
+            keys.len(),
+            values.len()
+        );
+    }
+    AgoType::Struct(keys.iter().cloned().zip(values).collect())
+}
+
+/// Splits a Struct into `[keys, values]`: a StringList of sorted keys and a
+/// ListAny of the matching values. This is the inverse of `tabula`.
+pub fn ex_tabula(s: &AgoType) -> AgoType {
+    let AgoType::Struct(map) = s else {
+        panic!("Cannot call 'ex_tabula' on type {:?}", s);
+    };
+    let mut entries: Vec<(&String, &AgoType)> = map.iter().collect();
+    entries.sort_by(|a, b| a.0.cmp(b.0));
+    let (keys, values): (Vec<String>, Vec<AgoType>) = entries
+        .into_iter()
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .unzip();
+    AgoType::ListAny(vec![AgoType::StringList(keys), AgoType::ListAny(values)])
+}
+
+/// Returns a Struct's keys as a StringList sorted ascending.
+pub fn claves_ordinatae(s: &AgoType) -> AgoType {
+    let AgoType::Struct(map) = s else {
+        panic!("Cannot call 'claves_ordinatae' on type {:?}", s);
+    };
+    let mut keys: Vec<String> = map.keys().cloned().collect();
+    keys.sort();
+    AgoType::StringList(keys)
+}
+
+/// Returns a Struct's values in ascending key order as the typed list for
+/// their shared primitive type (e.g. all-String values give a StringList).
+/// Panics naming the first key whose value breaks that type. This is the
+/// values counterpart of `claves_ordinatae`; an empty Struct gives an empty
+/// ListAny.
+pub fn valores_ordinati(s: &AgoType) -> AgoType {
+    let AgoType::Struct(map) = s else {
+        panic!("Cannot call 'valores_ordinati' on type {:?}", s);
+    };
+    let mut entries: Vec<(&String, &AgoType)> = map.iter().collect();
+    entries.sort_by(|a, b| a.0.cmp(b.0));
+    let Some((_, first)) = entries.first() else {
+        return AgoType::ListAny(Vec::new());
+    };
+    // A compound first value matches no typed list, so it is reported too.
+    let template = typed_list_for(first).unwrap_or(AgoType::ListAny(Vec::new()));
+    for (key, value) in &entries {
+        if typed_list_for(value).as_ref() != Some(&template) {
+            panic!(
+                "valores_ordinati expects values of one primitive type, but key '{}' holds {:?}",
+                key, value
+            );
+        }
+    }
+    collect_like(
+        &template,
+        entries.into_iter().map(|(_, v)| v.clone()).collect(),
+    )
+}
+
+/// Returns a Struct's entries as a ListAny of `[key, value]` pairs sorted by
+/// key, for iteration in a stable order.
+pub fn paria_ordinata(s: &AgoType) -> AgoType {
+    let AgoType::Struct(map) = s else {
+        panic!("Cannot call 'paria_ordinata' on type {:?}", s);
+    };
+    let mut entries: Vec<(&String, &AgoType)> = map.iter().collect();
+    entries.sort_by(|a, b| a.0.cmp(b.0));
+    AgoType::ListAny(
+        entries
+            .into_iter()
+            .map(|(k, v)| AgoType::ListAny(vec![AgoType::String(k.clone()), v.clone()]))
+            .collect(),
+    )
+}
+
+/// Accumulates values for generated loops and comprehensions, producing the
+/// narrowest list that holds them: a typed list when every value is the same
+/// primitive type, otherwise a ListAny. Nothing pushed gives an empty ListAny.
+#[derive(Debug, Default)]
+pub struct AgoCollector {
+    items: Vec<AgoType>,
+    // Empty typed list matching every item so far, or None once mixed.
+    template: Option<AgoType>,
+}
+
+impl AgoCollector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a value, widening to ListAny if its type differs from the rest.
+    pub fn push(&mut self, v: AgoType) {
+        if self.items.is_empty() {
+            self.template = typed_list_for(&v);
+        } else if self.template.is_some() && typed_list_for(&v) != self.template {
+            self.template = None;
+        }
+        self.items.push(v);
+    }
+
+    /// Returns the collected values as a list.
+    pub fn finish(self) -> AgoType {
+        match self.template {
+            Some(template) => collect_like(&template, self.items),
+            None => AgoType::ListAny(self.items),
+        }
+    }
+}
@@ -1,15 +1,45 @@
-use crate::types::{AgoInt, AgoType};
+use crate::iterators::into_iter;
+use crate::types::{AgoInt, AgoLambda, AgoListAny, AgoRange, AgoType, TargetType};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::io::Write;
+use std::rc::Rc;
 
-/// Prints a string to stdout. Returns Null.
-/// Name ends in -i (returns null/inanis)
-pub fn dici(val: &AgoType) -> AgoType {
-    match val {
-        AgoType::String(s) => println!("{}", s),
-        _ => panic!("dici expects a String, got {:?}", val),
+/// Helper to get the printable form of any value via the String cast.
+fn printable(val: &AgoType) -> String {
+    match val.as_type(TargetType::String) {
+        AgoType::String(s) => s,
+        _ => unreachable!(),
     }
+}
+
+/// Writes any value followed by a newline to `out`. Returns Null.
+/// This is the testable core of `dici`.
+pub fn dici_to(out: &mut impl Write, val: &AgoType) -> AgoType {
+    writeln!(out, "{}", printable(val)).expect("Failed to write output");
     AgoType::Null
 }
 
+/// Writes any value to `out` without a trailing newline, then flushes.
+/// This is the testable core of `scribe`.
+pub fn scribe_to(out: &mut impl Write, val: &AgoType) -> AgoType {
+    write!(out, "{}", printable(val)).expect("Failed to write output");
+    out.flush().expect("Failed to flush output");
+    AgoType::Null
+}
+
+/// Prints any value to stdout, followed by a newline. Returns Null.
+/// Name ends in -i (returns null/inanis)
+pub fn dici(val: &AgoType) -> AgoType {
+    dici_to(&mut std::io::stdout(), val)
+}
+
+/// Prints any value to stdout without a trailing newline. Returns Null.
+pub fn scribe(val: &AgoType) -> AgoType {
+    scribe_to(&mut std::io::stdout(), val)
+}
+
 // writes a string to a file named filename, fails otherwise.
 // names end in -i (returns null/inanis)
 pub fn scribi(filename: &AgoType, content: &AgoType) -> AgoType {
@@ -55,6 +85,740 @@ pub fn apertu(val: &AgoType) -> AgoType {
     }
 }
 
+/// Substitutes each `{}` in a String template with the String form of the
+/// next element of a ListAny. `{{` and `}}` produce literal braces.
+/// Panics if the placeholder count doesn't match the argument count.
+pub fn formatta(template: &AgoType, args: &AgoType) -> AgoType {
+    let (template, args) = match (template, args) {
+        (AgoType::String(t), AgoType::ListAny(a)) => (t, a),
+        _ => panic!(
+            "formatta expects a String template and a ListAny of arguments, got {:?} and {:?}",
+            template, args
+        ),
+    };
+    let mut result = String::new();
+    let mut placeholders = 0;
+    let mut chars = template.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (c, chars.peek()) {
+            ('{', Some('{')) | ('}', Some('}')) => {
+                result.push(c);
+                chars.next();
+            }
+            ('{', Some('}')) => {
+                chars.next();
+                if let Some(arg) = args.get(placeholders) {
+                    result.push_str(&printable(arg));
+                }
+                placeholders += 1;
+            }
+            ('{', _) | ('}', _) => panic!("Unmatched '{}' in format template '{}'", c, template),
+            _ => result.push(c),
+        }
+    }
+    if placeholders != args.len() {
+        panic!(
+            "Format template has {} placeholders, but {} arguments were given",
+            placeholders,
+            args.len()
+        );
+    }
+    AgoType::String(result)
+}
+
+/// Joins the String forms of a list's (or Range's) elements with a custom
+/// separator. Structs become `key: value` entries, sorted by key.
+/// Unlike the String cast, which always joins with a newline, the caller
+/// picks the separator.
+pub fn ad_textum(val: &AgoType, separator: &AgoType) -> AgoType {
+    let separator = match separator {
+        AgoType::String(s) => s,
+        other => panic!("ad_textum separator must be a String, but got {:?}", other),
+    };
+    let parts: Vec<String> = match val {
+        AgoType::IntList(_)
+        | AgoType::FloatList(_)
+        | AgoType::BoolList(_)
+        | AgoType::StringList(_)
+        | AgoType::ListAny(_)
+        | AgoType::Range(_) => into_iter(val).map(|item| printable(&item)).collect(),
+        AgoType::Struct(map) => {
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_by(|a, b| a.0.cmp(b.0));
+            entries
+                .into_iter()
+                .map(|(key, value)| format!("{}: {}", key, printable(value)))
+                .collect()
+        }
+        other => panic!("Cannot call 'ad_textum' on type {:?}", other),
+    };
+    AgoType::String(parts.join(separator))
+}
+
+/// Returns the current Unix time in milliseconds as an Int.
+pub fn tempus() -> AgoType {
+    let elapsed = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_else(|e| panic!("System clock is set before the Unix epoch: {}", e));
+    AgoType::Int(elapsed.as_millis() as AgoInt)
+}
+
+/// Reads an environment variable. Returns its value as a String, or Null if
+/// it is unset (or not valid Unicode).
+pub fn ambiens(name: &AgoType) -> AgoType {
+    match name {
+        AgoType::String(key) => std::env::var(key)
+            .map(AgoType::String)
+            .unwrap_or(AgoType::Null),
+        _ => panic!("ambiens expects a String variable name, got {:?}", name),
+    }
+}
+
+/// Builds the `argumenta` StringList from a raw argument iterator, dropping
+/// the leading program name.
+pub fn args_from(iter: impl Iterator<Item = String>) -> AgoType {
+    AgoType::StringList(iter.skip(1).collect())
+}
+
+/// Returns the command-line arguments, excluding the program name.
+pub fn argumenta() -> AgoType {
+    args_from(std::env::args())
+}
+
+/// Checks whether a String starts with the given prefix.
+pub fn incipit(s: &AgoType, prefix: &AgoType) -> AgoType {
+    match (s, prefix) {
+        (AgoType::String(s), AgoType::String(p)) => AgoType::Bool(s.starts_with(p.as_str())),
+        _ => panic!("incipit expects two Strings, got {:?} and {:?}", s, prefix),
+    }
+}
+
+/// Checks whether a String ends with the given suffix.
+pub fn desinit(s: &AgoType, suffix: &AgoType) -> AgoType {
+    match (s, suffix) {
+        (AgoType::String(s), AgoType::String(p)) => AgoType::Bool(s.ends_with(p.as_str())),
+        _ => panic!("desinit expects two Strings, got {:?} and {:?}", s, suffix),
+    }
+}
+
+/// Splits a String on a delimiter into at most `max` pieces, leaving the
+/// remainder (delimiters included) in the final piece, like Rust's `splitn`.
+pub fn finde_n(haystack: &AgoType, delimiter: &AgoType, max: &AgoType) -> AgoType {
+    match (haystack, delimiter, max) {
+        (AgoType::String(h), AgoType::String(d), AgoType::Int(n)) if *n > 0 => AgoType::StringList(
+            h.splitn(usize::try_from(*n).unwrap_or(usize::MAX), d.as_str())
+                .map(|piece| piece.to_string())
+                .collect(),
+        ),
+        (AgoType::String(_), AgoType::String(_), AgoType::Int(n)) => {
+            panic!("finde_n max must be a positive Int, got {}", n)
+        }
+        _ => panic!(
+            "finde_n expects two Strings and an Int, got {:?}, {:?}, and {:?}",
+            haystack, delimiter, max
+        ),
+    }
+}
+
+/// Splits a String into lines, accepting both `\n` and `\r\n` endings.
+/// A trailing line ending does not produce an extra empty line.
+pub fn lineae(s: &AgoType) -> AgoType {
+    match s {
+        AgoType::String(s) => AgoType::StringList(s.lines().map(|l| l.to_string()).collect()),
+        _ => panic!("lineae expects a String, got {:?}", s),
+    }
+}
+
+/// Prepends `prefix` to every line of a String, keeping each line's original
+/// `\n` or `\r\n` ending. Blank lines are prefixed too; a trailing line ending
+/// does not start a new line, so it gets no prefix.
+pub fn indenta(s: &AgoType, prefix: &AgoType) -> AgoType {
+    match (s, prefix) {
+        (AgoType::String(s), AgoType::String(p)) => AgoType::String(
+            s.split_inclusive('\n')
+                .map(|line| format!("{}{}", p, line))
+                .collect(),
+        ),
+        _ => panic!("indenta expects two Strings, got {:?} and {:?}", s, prefix),
+    }
+}
+
+/// Splits a String into words on runs of Unicode whitespace. Leading,
+/// trailing, and repeated whitespace never produce empty words.
+pub fn verba(s: &AgoType) -> AgoType {
+    match s {
+        AgoType::String(s) => {
+            AgoType::StringList(s.split_whitespace().map(|w| w.to_string()).collect())
+        }
+        _ => panic!("verba expects a String, got {:?}", s),
+    }
+}
+
+/// Checks, without panicking, whether a String parses as an Int or Float once
+/// surrounding whitespace is trimmed. A leading sign is accepted. Note that
+/// the String casts do not trim, so trim before casting padded input.
+pub fn est_numerus(s: &AgoType) -> AgoType {
+    match s {
+        AgoType::String(s) => {
+            let t = s.trim();
+            AgoType::Bool(t.parse::<i128>().is_ok() || t.parse::<f64>().is_ok())
+        }
+        _ => panic!("est_numerus expects a String, got {:?}", s),
+    }
+}
+
+/// Returns up to `length` characters of a String starting at char index
+/// `start`. A negative `start` counts from the end. Requests running past the
+/// end are clamped instead of panicking.
+pub fn sub_chorda(s: &AgoType, start: &AgoType, length: &AgoType) -> AgoType {
+    let (s, start, length) = match (s, start, length) {
+        (AgoType::String(s), AgoType::Int(start), AgoType::Int(length)) => (s, *start, *length),
+        _ => panic!(
+            "sub_chorda expects a String, an Int start, and an Int length, got {:?}, {:?}, and {:?}",
+            s, start, length
+        ),
+    };
+    if length < 0 {
+        panic!("sub_chorda length must be non-negative, but got {}", length);
+    }
+    let char_count = s.chars().count() as AgoInt;
+    let start = if start < 0 {
+        (char_count + start).max(0)
+    } else {
+        start
+    };
+    AgoType::String(
+        s.chars()
+            .skip(usize::try_from(start).unwrap_or(usize::MAX))
+            .take(usize::try_from(length).unwrap_or(usize::MAX))
+            .collect(),
+    )
+}
+
+/// Helper to format an Int's magnitude in base 2 or 16, with a leading `-`
+/// for negative values.
+fn format_radix(n: &AgoType, radix: u32, func: &str) -> AgoType {
+    match n {
+        AgoType::Int(n) => {
+            let digits = match radix {
+                2 => format!("{:b}", n.unsigned_abs()),
+                16 => format!("{:x}", n.unsigned_abs()),
+                _ => unreachable!(),
+            };
+            let sign = if *n < 0 { "-" } else { "" };
+            AgoType::String(format!("{}{}", sign, digits))
+        }
+        _ => panic!("{} expects an Int, got {:?}", func, n),
+    }
+}
+
+/// Helper to parse a String of digits in `radix` (optionally signed) to an Int.
+fn parse_radix(s: &AgoType, radix: u32, func: &str) -> AgoType {
+    match s {
+        AgoType::String(digits) => i128::from_str_radix(digits, radix)
+            .map(AgoType::Int)
+            .unwrap_or_else(|e| {
+                panic!(
+                    "{}: cannot parse '{}' in base {}: {}",
+                    func, digits, radix, e
+                )
+            }),
+        _ => panic!("{} expects a String, got {:?}", func, s),
+    }
+}
+
+/// Formats an Int as a base-2 String, e.g. -5 -> "-101".
+pub fn ad_binarium(n: &AgoType) -> AgoType {
+    format_radix(n, 2, "ad_binarium")
+}
+
+/// Formats an Int as a lowercase base-16 String, e.g. 255 -> "ff".
+pub fn ad_hexadecimale(n: &AgoType) -> AgoType {
+    format_radix(n, 16, "ad_hexadecimale")
+}
+
+/// Parses a base-2 String (as produced by `ad_binarium`) back to an Int.
+pub fn ex_binarium(s: &AgoType) -> AgoType {
+    parse_radix(s, 2, "ex_binarium")
+}
+
+/// Parses a base-16 String (as produced by `ad_hexadecimale`) back to an Int.
+pub fn ex_hexadecimale(s: &AgoType) -> AgoType {
+    parse_radix(s, 16, "ex_hexadecimale")
+}
+
+/// Parses a String of digits in the given base (2-36) to an Int.
+pub fn ex_chorda_basi(s: &AgoType, base: &AgoType) -> AgoType {
+    match base {
+        AgoType::Int(b) if (2..=36).contains(b) => parse_radix(s, *b as u32, "ex_chorda_basi"),
+        _ => panic!(
+            "ex_chorda_basi: cannot parse {:?} in base {:?}: base must be an Int from 2 to 36",
+            s, base
+        ),
+    }
+}
+
+/// Returns the number of set bits in an Int. Negative values count the bits
+/// of their 128-bit two's-complement form, so `-1` gives 128.
+pub fn numerus_bitorum(n: &AgoType) -> AgoType {
+    match n {
+        AgoType::Int(i) => AgoType::Int(i.count_ones() as AgoInt),
+        _ => panic!("numerus_bitorum expects an Int, but got {:?}", n),
+    }
+}
+
+/// Returns the number of bits needed to write an Int's magnitude in binary
+/// (the length of `ad_binarium` without the sign). `0` needs 0 bits.
+pub fn longitudo_bitorum(n: &AgoType) -> AgoType {
+    match n {
+        AgoType::Int(i) => AgoType::Int((u128::BITS - i.unsigned_abs().leading_zeros()) as AgoInt),
+        _ => panic!("longitudo_bitorum expects an Int, but got {:?}", n),
+    }
+}
+
+/// Roman numeral symbols, largest first, including subtractive pairs.
+const ROMAN_NUMERALS: [(AgoInt, &str); 13] = [
+    (1000, "M"),
+    (900, "CM"),
+    (500, "D"),
+    (400, "CD"),
+    (100, "C"),
+    (90, "XC"),
+    (50, "L"),
+    (40, "XL"),
+    (10, "X"),
+    (9, "IX"),
+    (5, "V"),
+    (4, "IV"),
+    (1, "I"),
+];
+
+/// Helper to write 1..=3999 in canonical Roman numerals.
+fn roman(mut n: AgoInt) -> String {
+    let mut out = String::new();
+    for (value, symbol) in ROMAN_NUMERALS {
+        while n >= value {
+            out.push_str(symbol);
+            n -= value;
+        }
+    }
+    out
+}
+
+/// Converts an Int from 1 to 3999 to a Roman numeral String.
+pub fn ad_romanum(n: &AgoType) -> AgoType {
+    match n {
+        AgoType::Int(i) if (1..=3999).contains(i) => AgoType::String(roman(*i)),
+        AgoType::Int(i) => panic!("ad_romanum: {} is outside the range 1 to 3999", i),
+        _ => panic!("ad_romanum expects an Int, but got {:?}", n),
+    }
+}
+
+/// Parses an upper-case Roman numeral String (as produced by `ad_romanum`)
+/// back to an Int. Non-canonical forms such as "IIII" or "IC" panic.
+pub fn ex_romanum(s: &AgoType) -> AgoType {
+    let AgoType::String(text) = s else {
+        panic!("ex_romanum expects a String, but got {:?}", s);
+    };
+    let mut rest = text.as_str();
+    let mut total = 0;
+    for (value, symbol) in ROMAN_NUMERALS {
+        while let Some(tail) = rest.strip_prefix(symbol) {
+            total += value;
+            rest = tail;
+        }
+    }
+    if !rest.is_empty() || total == 0 || roman(total) != *text {
+        panic!("ex_romanum: '{}' is not a valid Roman numeral", text);
+    }
+    AgoType::Int(total)
+}
+
+/// Minimal JSON reader used by `ex_json_lineae`. Objects become Structs,
+/// arrays ListAny, integral numbers Int, other numbers Float, and null Null.
+struct JsonParser<'a> {
+    chars: std::iter::Peekable<std::str::Chars<'a>>,
+    depth: usize,
+}
+
+/// Deepest nesting of arrays and objects `JsonParser` accepts, so hostile
+/// input fails with an error instead of overflowing the stack.
+const JSON_MAX_DEPTH: usize = 128;
+
+impl JsonParser<'_> {
+    fn skip_whitespace(&mut self) {
+        while self.chars.next_if(|c| c.is_whitespace()).is_some() {}
+    }
+
+    fn expect(&mut self, wanted: char) -> Result<(), String> {
+        self.skip_whitespace();
+        match self.chars.next() {
+            Some(c) if c == wanted => Ok(()),
+            Some(c) => Err(format!("expected '{}' but found '{}'", wanted, c)),
+            None => Err(format!("expected '{}' but found end of input", wanted)),
+        }
+    }
+
+    fn value(&mut self) -> Result<AgoType, String> {
+        self.skip_whitespace();
+        match self.chars.peek().copied() {
+            Some(open @ ('{' | '[')) => {
+                if self.depth == JSON_MAX_DEPTH {
+                    return Err("nesting too deep".to_string());
+                }
+                self.depth += 1;
+                let nested = if open == '{' {
+                    self.object()
+                } else {
+                    self.array()
+                };
+                self.depth -= 1;
+                nested
+            }
+            Some('"') => self.string().map(AgoType::String),
+            Some(c) if c == '-' || c.is_ascii_digit() => self.number(),
+            Some(c) if c.is_alphabetic() => {
+                let mut word = String::new();
+                while let Some(c) = self.chars.next_if(|c| c.is_alphabetic()) {
+                    word.push(c);
+                }
+                match word.as_str() {
+                    "true" => Ok(AgoType::Bool(true)),
+                    "false" => Ok(AgoType::Bool(false)),
+                    "null" => Ok(AgoType::Null),
+                    _ => Err(format!("unexpected word '{}'", word)),
+                }
+            }
+            Some(c) => Err(format!("unexpected character '{}'", c)),
+            None => Err("unexpected end of input".to_string()),
+        }
+    }
+
+    fn object(&mut self) -> Result<AgoType, String> {
+        self.expect('{')?;
+        let mut map = HashMap::new();
+        self.skip_whitespace();
+        if self.chars.next_if_eq(&'}').is_some() {
+            return Ok(AgoType::Struct(map));
+        }
+        loop {
+            self.skip_whitespace();
+            let key = self.string()?;
+            self.expect(':')?;
+            map.insert(key, self.value()?);
+            self.skip_whitespace();
+            match self.chars.next() {
+                Some(',') => continue,
+                Some('}') => return Ok(AgoType::Struct(map)),
+                _ => return Err("expected ',' or '}' in object".to_string()),
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<AgoType, String> {
+        self.expect('[')?;
+        let mut items = Vec::new();
+        self.skip_whitespace();
+        if self.chars.next_if_eq(&']').is_some() {
+            return Ok(AgoType::ListAny(items));
+        }
+        loop {
+            items.push(self.value()?);
+            self.skip_whitespace();
+            match self.chars.next() {
+                Some(',') => continue,
+                Some(']') => return Ok(AgoType::ListAny(items)),
+                _ => return Err("expected ',' or ']' in array".to_string()),
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        self.expect('"')?;
+        let mut out = String::new();
+        loop {
+            match self.chars.next() {
+                Some('"') => return Ok(out),
+                Some('\\') => {
+                    let escaped = match self.chars.next() {
+                        Some('n') => '\n',
+                        Some('t') => '\t',
+                        Some('r') => '\r',
+                        Some('b') => '\u{8}',
+                        Some('f') => '\u{c}',
+                        Some('u') => self.unicode_escape()?,
+                        Some(c @ ('"' | '\\' | '/')) => c,
+                        Some(c) => return Err(format!("invalid escape '\\{}'", c)),
+                        None => return Err("unterminated string".to_string()),
+                    };
+                    out.push(escaped);
+                }
+                Some(c) => out.push(c),
+                None => return Err("unterminated string".to_string()),
+            }
+        }
+    }
+
+    fn hex4(&mut self) -> Result<u32, String> {
+        let hex: String = self.chars.by_ref().take(4).collect();
+        if hex.len() != 4 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(format!("invalid unicode escape '\\u{}'", hex));
+        }
+        Ok(u32::from_str_radix(&hex, 16).expect("validated hex digits"))
+    }
+
+    /// Decodes the digits after `\u`, joining a UTF-16 surrogate pair when a
+    /// high surrogate is followed by an escaped low surrogate.
+    fn unicode_escape(&mut self) -> Result<char, String> {
+        let high = self.hex4()?;
+        let code = match high {
+            0xD800..=0xDBFF => {
+                if self.chars.next() != Some('\\') || self.chars.next() != Some('u') {
+                    return Err(format!("unpaired surrogate '\\u{:x}'", high));
+                }
+                let low = self.hex4()?;
+                if !(0xDC00..=0xDFFF).contains(&low) {
+                    return Err(format!("unpaired surrogate '\\u{:x}'", high));
+                }
+                0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+            }
+            _ => high,
+        };
+        char::from_u32(code).ok_or_else(|| format!("unpaired surrogate '\\u{:x}'", code))
+    }
+
+    fn number(&mut self) -> Result<AgoType, String> {
+        let mut text = String::new();
+        while let Some(c) = self
+            .chars
+            .next_if(|c| c.is_ascii_digit() || matches!(c, '-' | '+' | '.' | 'e' | 'E'))
+        {
+            text.push(c);
+        }
+        if !is_json_number(&text) {
+            return Err(format!("invalid number '{}'", text));
+        }
+        if !text.contains(['.', 'e', 'E']) {
+            if let Ok(n) = text.parse() {
+                return Ok(AgoType::Int(n));
+            }
+        }
+        text.parse()
+            .map(AgoType::Float)
+            .map_err(|_| format!("invalid number '{}'", text))
+    }
+}
+
+/// Helper to check JSON number syntax: an optional minus, an integer part
+/// without leading zeros, then an optional fraction and exponent.
+fn is_json_number(text: &str) -> bool {
+    fn digits(s: &str) -> usize {
+        s.len() - s.trim_start_matches(|c: char| c.is_ascii_digit()).len()
+    }
+    let rest = text.strip_prefix('-').unwrap_or(text);
+    let int_len = digits(rest);
+    if int_len == 0 || (int_len > 1 && rest.starts_with('0')) {
+        return false;
+    }
+    let mut rest = &rest[int_len..];
+    if let Some(frac) = rest.strip_prefix('.') {
+        let n = digits(frac);
+        if n == 0 {
+            return false;
+        }
+        rest = &frac[n..];
+    }
+    if let Some(exp) = rest.strip_prefix(['e', 'E']) {
+        let exp = exp.strip_prefix(['+', '-']).unwrap_or(exp);
+        let n = digits(exp);
+        if n == 0 {
+            return false;
+        }
+        rest = &exp[n..];
+    }
+    rest.is_empty()
+}
+
+/// Parses newline-delimited JSON (one value per line) into a ListAny.
+/// Blank lines are skipped; a malformed line panics with its line number.
+pub fn ex_json_lineae(text: &AgoType) -> AgoType {
+    let AgoType::String(text) = text else {
+        panic!("ex_json_lineae expects a String, but got {:?}", text);
+    };
+    let mut values = Vec::new();
+    for (number, line) in text.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let mut parser = JsonParser {
+            chars: line.chars().peekable(),
+            depth: 0,
+        };
+        let parsed = parser.value().and_then(|value| {
+            parser.skip_whitespace();
+            match parser.chars.next() {
+                None => Ok(value),
+                Some(c) => Err(format!("unexpected trailing character '{}'", c)),
+            }
+        });
+        match parsed {
+            Ok(value) => values.push(value),
+            Err(e) => panic!("ex_json_lineae: line {}: {}", number + 1, e),
+        }
+    }
+    AgoType::ListAny(values)
+}
+
+/// Parses simple CSV into a ListAny of StringList rows. Fields may be
+/// double-quoted to hold commas, newlines, and `""` for an embedded quote.
+pub fn ex_csv(text: &AgoType) -> AgoType {
+    let AgoType::String(text) = text else {
+        panic!("ex_csv expects a String, but got {:?}", text);
+    };
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut pending = false;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        pending = true;
+        match c {
+            '"' if in_quotes => {
+                if chars.next_if_eq(&'"').is_some() {
+                    field.push('"');
+                } else {
+                    in_quotes = false;
+                }
+            }
+            _ if in_quotes => field.push(c),
+            '"' => in_quotes = true,
+            ',' => row.push(std::mem::take(&mut field)),
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                row.push(std::mem::take(&mut field));
+                rows.push(AgoType::StringList(std::mem::take(&mut row)));
+                pending = false;
+            }
+            _ => field.push(c),
+        }
+    }
+    if in_quotes {
+        panic!("ex_csv: unterminated quoted field");
+    }
+    if pending {
+        row.push(field);
+        rows.push(AgoType::StringList(row));
+    }
+    AgoType::ListAny(rows)
+}
+
+/// Emits a ListAny of StringList rows as CSV, one row per line. Fields
+/// holding a comma, quote, or newline are quoted with `""` escaping. A row
+/// with no fields has no CSV form (a blank line reads back as `[""]`), so it
+/// panics.
+pub fn ad_csv(rows: &AgoType) -> AgoType {
+    let AgoType::ListAny(rows) = rows else {
+        panic!(
+            "ad_csv expects a ListAny of StringList rows, but got {:?}",
+            rows
+        );
+    };
+    let lines: Vec<String> = rows
+        .iter()
+        .enumerate()
+        .map(|(index, row)| {
+            let AgoType::StringList(fields) = row else {
+                panic!("ad_csv rows must be StringLists, but got {:?}", row);
+            };
+            if fields.is_empty() {
+                panic!("ad_csv cannot write empty row {}", index);
+            }
+            // A lone empty field is quoted so the row is not read back as a
+            // blank line.
+            if let [only] = fields.as_slice() {
+                if only.is_empty() {
+                    return "\"\"".to_string();
+                }
+            }
+            fields
+                .iter()
+                .map(|f| {
+                    if f.contains([',', '"', '\n', '\r']) {
+                        format!("\"{}\"", f.replace('"', "\"\""))
+                    } else {
+                        f.clone()
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(",")
+        })
+        .collect();
+    AgoType::String(lines.join("\n"))
+}
+
+/// Helper to pad a String to `width` characters with a single-char fill.
+/// Strings already at or beyond the width are returned unchanged.
+fn pad(s: &AgoType, width: &AgoType, fill: &AgoType, left: bool, func: &str) -> AgoType {
+    let (s, width, fill) = match (s, width, fill) {
+        (AgoType::String(s), AgoType::Int(w), AgoType::String(f)) => (s, *w, f),
+        _ => panic!(
+            "{} expects a String, an Int width, and a String fill, got {:?}, {:?}, and {:?}",
+            func, s, width, fill
+        ),
+    };
+    let mut fill_chars = fill.chars();
+    let fill = match (fill_chars.next(), fill_chars.next()) {
+        (Some(c), None) => c,
+        _ => panic!("{} fill must be a single character, got '{}'", func, fill),
+    };
+    let width = usize::try_from(width.max(0))
+        .unwrap_or_else(|_| panic!("{} width {} is too large", func, width));
+    let missing = width.saturating_sub(s.chars().count());
+    let padding: String = std::iter::repeat_n(fill, missing).collect();
+    if left {
+        AgoType::String(format!("{}{}", padding, s))
+    } else {
+        AgoType::String(format!("{}{}", s, padding))
+    }
+}
+
+/// Pads a String on the left to `width` characters (right-justifies it).
+pub fn imple_sinistra(s: &AgoType, width: &AgoType, fill: &AgoType) -> AgoType {
+    pad(s, width, fill, true, "imple_sinistra")
+}
+
+/// Pads a String on the right to `width` characters (left-justifies it).
+pub fn imple_dextra(s: &AgoType, width: &AgoType, fill: &AgoType) -> AgoType {
+    pad(s, width, fill, false, "imple_dextra")
+}
+
+/// Converts an Int Unicode scalar value to a single-character String.
+/// Panics on surrogates and values above U+10FFFF. Backs Ago's `chr`.
+pub fn ad_chr(code: &AgoType) -> AgoType {
+    match code {
+        AgoType::Int(i) => match u32::try_from(*i).ok().and_then(char::from_u32) {
+            Some(c) => AgoType::String(c.to_string()),
+            None => panic!("ad_chr: {} is not a valid Unicode scalar value", i),
+        },
+        _ => panic!("ad_chr expects an Int, but got {:?}", code),
+    }
+}
+
+/// Returns the Int codepoint of a single-character String. Backs Ago's `ord`.
+pub fn ex_chr(s: &AgoType) -> AgoType {
+    match s {
+        AgoType::String(text) => {
+            let mut chars = text.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => AgoType::Int(c as AgoInt),
+                _ => panic!("ex_chr expects a single character, got '{}'", text),
+            }
+        }
+        _ => panic!("ex_chr expects a String, but got {:?}", s),
+    }
+}
+
 /// Returns the string name of an AgoType.
 /// Name ends in -es (returns string)
 pub fn species(val: &AgoType) -> AgoType {
@@ -75,6 +839,551 @@ pub fn species(val: &AgoType) -> AgoType {
     AgoType::String(type_name.to_string())
 }
 
+/// Checks whether a value's `species` matches the given type-name String.
+/// This backs Ago's `x is Int` checks.
+pub fn est(val: &AgoType, type_name: &AgoType) -> AgoType {
+    match type_name {
+        AgoType::String(name) => AgoType::Bool(species(val) == AgoType::String(name.clone())),
+        other => panic!("est expects a type-name String, but got {:?}", other),
+    }
+}
+
+/// Checks strictly whether a value is Null. Unlike casting to Bool, empty
+/// lists, empty Strings, and `false` are not treated as Null.
+pub fn est_inanis(val: &AgoType) -> AgoType {
+    AgoType::Bool(matches!(val, AgoType::Null))
+}
+
+/// Helper to collect the `species` name of every element of a list.
+fn element_species(list: &AgoType, func: &str) -> Vec<String> {
+    match list {
+        AgoType::IntList(_)
+        | AgoType::FloatList(_)
+        | AgoType::BoolList(_)
+        | AgoType::StringList(_)
+        | AgoType::ListAny(_) => into_iter(list)
+            .map(|item| match species(&item) {
+                AgoType::String(name) => name,
+                _ => unreachable!(),
+            })
+            .collect(),
+        other => panic!("{} expects a list, but got {:?}", func, other),
+    }
+}
+
+/// Checks whether all elements of a list share the same type.
+/// Empty lists are vacuously homogeneous, though `typus_communis` gives Null
+/// for them since there is no element type to name.
+pub fn homogeneum(list: &AgoType) -> AgoType {
+    let names = element_species(list, "homogeneum");
+    AgoType::Bool(names.windows(2).all(|pair| pair[0] == pair[1]))
+}
+
+/// Returns the type name shared by all elements of a list, or "mixtum" if the
+/// elements differ. Empty lists are homogeneous but have no type to name, so
+/// they yield Null.
+pub fn typus_communis(list: &AgoType) -> AgoType {
+    let names = element_species(list, "typus_communis");
+    match names.first() {
+        None => AgoType::Null,
+        Some(first) if names.iter().all(|name| name == first) => AgoType::String(first.clone()),
+        _ => AgoType::String("mixtum".to_string()),
+    }
+}
+
+/// Returns a copy of any value that is fully independent of the original.
+/// AgoType owns all of its data (no shared references), so this clone is deep:
+/// mutating the source afterwards never affects the copy, and vice versa.
+pub fn replica(val: &AgoType) -> AgoType {
+    val.clone()
+}
+
+/// Helper to count the integers a Range spans. Panics if the count does not
+/// fit in an Int, e.g. for the full `i128` range.
+fn range_len(range: &AgoRange) -> AgoInt {
+    if range.end < range.start {
+        return 0;
+    }
+    range
+        .end
+        .checked_sub(range.start)
+        .and_then(|span| span.checked_add(range.inclusive as AgoInt))
+        .unwrap_or_else(|| panic!("Range length does not fit in an Int: {:?}", range))
+}
+
+/// Returns the number of elements in a list, characters in a String, entries
+/// in a Struct, or integers spanned by a Range. Panics for scalar types.
+pub fn longitudo(val: &AgoType) -> AgoType {
+    let len = match val {
+        AgoType::IntList(v) => v.len(),
+        AgoType::FloatList(v) => v.len(),
+        AgoType::BoolList(v) => v.len(),
+        AgoType::StringList(v) => v.len(),
+        AgoType::ListAny(v) => v.len(),
+        AgoType::String(s) => s.chars().count(),
+        AgoType::Struct(map) => map.len(),
+        AgoType::Range(range) => return AgoType::Int(range_len(range)),
+        other => panic!("Cannot take the length of {:?}", other),
+    };
+    AgoType::Int(len as AgoInt)
+}
+
+/// Checks whether a collection, String, or Range is empty. Null counts as
+/// empty. Panics for Ints, Floats, and Bools, which have no notion of emptiness.
+pub fn vacuum(val: &AgoType) -> AgoType {
+    match val {
+        AgoType::Null => AgoType::Bool(true),
+        AgoType::Int(_) | AgoType::Float(_) | AgoType::Bool(_) => {
+            panic!("Cannot check emptiness of {:?}", val)
+        }
+        AgoType::Range(range) => AgoType::Bool(if range.inclusive {
+            range.start > range.end
+        } else {
+            range.start >= range.end
+        }),
+        _ => AgoType::Bool(longitudo(val) == AgoType::Int(0)),
+    }
+}
+
+/// Converts a Bool to the literal Float 1.0 (true) or 0.0 (false).
+/// Unlike the `Bool -> Float` cast, which is deliberately whimsical
+/// (true -> 4.2, false -> -3.9), this is safe for real numeric code.
+pub fn bool_ad_float(val: &AgoType) -> AgoType {
+    match val {
+        AgoType::Bool(b) => AgoType::Float(if *b { 1.0 } else { 0.0 }),
+        _ => panic!("bool_ad_float expects a Bool, but got {:?}", val),
+    }
+}
+
+/// Helper to map a FloatList to an IntList with an explicit rounding mode.
+/// Panics on NaN or values outside the i128 range.
+fn rounded_list(val: &AgoType, mode: fn(f64) -> f64, func: &str) -> AgoType {
+    match val {
+        AgoType::FloatList(list) => AgoType::IntList(
+            list.iter()
+                .map(|&f| {
+                    let r = mode(f);
+                    if r.is_nan() || r < i128::MIN as f64 || r >= i128::MAX as f64 {
+                        panic!("{}: {} is out of range for an Int", func, f);
+                    }
+                    r as i128
+                })
+                .collect(),
+        ),
+        _ => panic!("{} expects a FloatList, but got {:?}", func, val),
+    }
+}
+
+/// Converts a FloatList to an IntList, rounding each element down.
+pub fn floor_list(val: &AgoType) -> AgoType {
+    rounded_list(val, f64::floor, "floor_list")
+}
+
+/// Converts a FloatList to an IntList, rounding each element up.
+pub fn ceil_list(val: &AgoType) -> AgoType {
+    rounded_list(val, f64::ceil, "ceil_list")
+}
+
+/// Converts a FloatList to an IntList, rounding each element to the nearest
+/// integer (halves round away from zero).
+pub fn round_list(val: &AgoType) -> AgoType {
+    rounded_list(val, f64::round, "round_list")
+}
+
+/// Rounds a Float to the given number of decimal places, returning a Float.
+/// Scales by a power of ten and rounds, so halves round away from zero
+/// (`rotundum_ad(0.125, 2)` is `0.13`). `places` must be a non-negative Int.
+pub fn rotundum_ad(val: &AgoType, places: &AgoType) -> AgoType {
+    let f = match val {
+        AgoType::Float(f) => *f,
+        _ => panic!("rotundum_ad expects a Float, but got {:?}", val),
+    };
+    let places = match places {
+        AgoType::Int(p) if *p >= 0 => i32::try_from(*p).unwrap_or(i32::MAX),
+        _ => panic!(
+            "Decimal places must be a non-negative Int, but got {:?}",
+            places
+        ),
+    };
+    let scale = 10f64.powi(places);
+    let scaled = f * scale;
+    // A value too large to scale has no fractional digits left to round.
+    if !scaled.is_finite() {
+        return AgoType::Float(f);
+    }
+    AgoType::Float(scaled.round() / scale)
+}
+
+/// Helper to read an Int or Float operand as an f64.
+fn numeric_value(val: &AgoType, func: &str) -> f64 {
+    match val {
+        AgoType::Int(i) => *i as f64,
+        AgoType::Float(f) => *f,
+        _ => panic!("{} expects an Int or Float, got {:?}", func, val),
+    }
+}
+
+/// Returns the sine of an angle in radians, as a Float.
+pub fn sinus(x: &AgoType) -> AgoType {
+    AgoType::Float(numeric_value(x, "sinus").sin())
+}
+
+/// Returns the cosine of an angle in radians, as a Float.
+pub fn cosinus(x: &AgoType) -> AgoType {
+    AgoType::Float(numeric_value(x, "cosinus").cos())
+}
+
+/// Returns the tangent of an angle in radians, as a Float.
+pub fn tangens(x: &AgoType) -> AgoType {
+    AgoType::Float(numeric_value(x, "tangens").tan())
+}
+
+/// Returns the natural logarithm of a positive number, as a Float.
+pub fn logarithmus(x: &AgoType) -> AgoType {
+    let x = numeric_value(x, "logarithmus");
+    if x <= 0.0 {
+        panic!("logarithmus is undefined for non-positive value {}", x);
+    }
+    AgoType::Float(x.ln())
+}
+
+/// Returns the logarithm of a positive number in the given base, as a Float.
+/// The base must be positive and not 1.
+pub fn logarithmus_base(x: &AgoType, base: &AgoType) -> AgoType {
+    let x = numeric_value(x, "logarithmus_base");
+    let base = numeric_value(base, "logarithmus_base");
+    if x <= 0.0 {
+        panic!("logarithmus_base is undefined for non-positive value {}", x);
+    }
+    if base <= 0.0 || base == 1.0 {
+        panic!("logarithmus_base is undefined for base {}", base);
+    }
+    AgoType::Float(x.log(base))
+}
+
+/// Returns e raised to the given power, as a Float.
+pub fn exponentialis(x: &AgoType) -> AgoType {
+    AgoType::Float(numeric_value(x, "exponentialis").exp())
+}
+
+/// Returns the sign of a number as an Int: -1, 0, or 1. Panics on NaN.
+pub fn signum(val: &AgoType) -> AgoType {
+    let sign = match val {
+        AgoType::Int(i) => i.signum(),
+        AgoType::Float(f) if f.is_nan() => panic!("signum is undefined for NaN"),
+        AgoType::Float(f) if *f == 0.0 => 0,
+        AgoType::Float(f) => f.signum() as AgoInt,
+        _ => panic!("signum expects an Int or Float, got {:?}", val),
+    };
+    AgoType::Int(sign)
+}
+
+/// Returns the absolute difference of two numbers. Two Ints give an Int
+/// (panicking on overflow); if either side is a Float the result is a Float.
+pub fn abs_differentia(left: &AgoType, right: &AgoType) -> AgoType {
+    match (left, right) {
+        (AgoType::Int(a), AgoType::Int(b)) => AgoType::Int(
+            a.checked_sub(*b)
+                .and_then(AgoInt::checked_abs)
+                .unwrap_or_else(|| panic!("Integer overflow in abs_differentia({}, {})", a, b)),
+        ),
+        _ => {
+            let a = numeric_value(left, "abs_differentia");
+            let b = numeric_value(right, "abs_differentia");
+            AgoType::Float((a - b).abs())
+        }
+    }
+}
+
+/// Checks whether an Int is even.
+pub fn par(val: &AgoType) -> AgoType {
+    match val {
+        AgoType::Int(i) => AgoType::Bool(i % 2 == 0),
+        _ => panic!("par expects an Int, got {:?}", val),
+    }
+}
+
+/// Helper to read a numeric list (IntList, FloatList, or a ListAny of Ints
+/// and Floats) as f64s. Panics on other types and on empty lists.
+fn numeric_list(list: &AgoType, func: &str) -> Vec<f64> {
+    let values: Vec<f64> = match list {
+        AgoType::IntList(_) | AgoType::FloatList(_) | AgoType::ListAny(_) => into_iter(list)
+            .map(|item| numeric_value(&item, func))
+            .collect(),
+        _ => panic!("{} expects a numeric list, but got {:?}", func, list),
+    };
+    if values.is_empty() {
+        panic!("{} of an empty list is undefined", func);
+    }
+    values
+}
+
+/// Returns the arithmetic mean of a numeric list, as a Float.
+pub fn media(list: &AgoType) -> AgoType {
+    let values = numeric_list(list, "media");
+    AgoType::Float(values.iter().sum::<f64>() / values.len() as f64)
+}
+
+/// Returns the median of a numeric list, as a Float. Even-length lists
+/// average the two middle values. The input is not modified.
+pub fn mediana(list: &AgoType) -> AgoType {
+    let mut values = numeric_list(list, "mediana");
+    values.sort_by(f64::total_cmp);
+    let mid = values.len() / 2;
+    if values.len().is_multiple_of(2) {
+        AgoType::Float((values[mid - 1] + values[mid]) / 2.0)
+    } else {
+        AgoType::Float(values[mid])
+    }
+}
+
+/// Helper to sum squared deviations from the mean of a numeric list.
+fn squared_deviations(list: &AgoType, func: &str) -> (f64, usize) {
+    let values = numeric_list(list, func);
+    let mean = match media(list) {
+        AgoType::Float(mean) => mean,
+        other => unreachable!("media returned {:?}", other),
+    };
+    let sum = values.iter().map(|v| (v - mean) * (v - mean)).sum();
+    (sum, values.len())
+}
+
+/// Returns the population standard deviation of a numeric list, as a Float.
+pub fn deviatio(list: &AgoType) -> AgoType {
+    let (sum, n) = squared_deviations(list, "deviatio");
+    AgoType::Float((sum / n as f64).sqrt())
+}
+
+/// Returns the sample (n - 1) standard deviation of a numeric list, as a
+/// Float. Panics on lists with fewer than two values.
+pub fn deviatio_sample(list: &AgoType) -> AgoType {
+    let (sum, n) = squared_deviations(list, "deviatio_sample");
+    if n < 2 {
+        panic!(
+            "deviatio_sample requires at least two values, but got {}",
+            n
+        );
+    }
+    AgoType::Float((sum / (n - 1) as f64).sqrt())
+}
+
+/// Helper to run Euclid's algorithm on the magnitudes of two Ints.
+fn gcd_magnitude(a: AgoInt, b: AgoInt) -> u128 {
+    let (mut a, mut b) = (a.unsigned_abs(), b.unsigned_abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Returns the greatest common divisor of two Ints (always non-negative).
+/// `maximus_divisor(0, 0)` is defined as 0.
+pub fn maximus_divisor(left: &AgoType, right: &AgoType) -> AgoType {
+    match (left, right) {
+        (AgoType::Int(a), AgoType::Int(b)) => AgoType::Int(
+            AgoInt::try_from(gcd_magnitude(*a, *b))
+                .unwrap_or_else(|_| panic!("Integer overflow in maximus_divisor")),
+        ),
+        _ => panic!(
+            "maximus_divisor expects two Ints, got {:?} and {:?}",
+            left, right
+        ),
+    }
+}
+
+/// Returns the least common multiple of two Ints (always non-negative).
+/// The result is 0 if either operand is 0. Panics on overflow.
+pub fn minimum_multiplum(left: &AgoType, right: &AgoType) -> AgoType {
+    match (left, right) {
+        (AgoType::Int(0), AgoType::Int(_)) | (AgoType::Int(_), AgoType::Int(0)) => AgoType::Int(0),
+        (AgoType::Int(a), AgoType::Int(b)) => {
+            let lcm = (a.unsigned_abs() / gcd_magnitude(*a, *b))
+                .checked_mul(b.unsigned_abs())
+                .and_then(|m| AgoInt::try_from(m).ok())
+                .unwrap_or_else(|| panic!("Integer overflow in minimum_multiplum({}, {})", a, b));
+            AgoType::Int(lcm)
+        }
+        _ => panic!(
+            "minimum_multiplum expects two Ints, got {:?} and {:?}",
+            left, right
+        ),
+    }
+}
+
+/// Computes `(base ^ exp) mod modulus` for Ints by square-and-multiply,
+/// reducing at every step so intermediate values never overflow. The result
+/// is in `0..modulus`. Panics on a negative exponent or non-positive modulus.
+pub fn potentia_modularis(base: &AgoType, exp: &AgoType, modulus: &AgoType) -> AgoType {
+    let (base, mut exp, modulus) = match (base, exp, modulus) {
+        (AgoType::Int(b), AgoType::Int(e), AgoType::Int(m)) => (*b, *e, *m),
+        _ => panic!(
+            "potentia_modularis expects three Ints, got {:?}, {:?}, and {:?}",
+            base, exp, modulus
+        ),
+    };
+    if modulus <= 0 {
+        panic!(
+            "potentia_modularis modulus must be positive, got {}",
+            modulus
+        );
+    }
+    if exp < 0 {
+        panic!(
+            "potentia_modularis exponent must be non-negative, got {}",
+            exp
+        );
+    }
+    // Both factors are below 2^127, so u128 products could still overflow;
+    // mul_mod adds by doubling instead.
+    let m = modulus as u128;
+    let mul_mod = |mut a: u128, mut b: u128| {
+        let mut product = 0u128;
+        while b > 0 {
+            if b & 1 == 1 {
+                product = (product + a) % m;
+            }
+            a = (a << 1) % m;
+            b >>= 1;
+        }
+        product
+    };
+    let mut result = 1 % m;
+    let mut b = base.rem_euclid(modulus) as u128;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, b);
+        }
+        b = mul_mod(b, b);
+        exp >>= 1;
+    }
+    AgoType::Int(result as AgoInt)
+}
+
+/// Advances a splitmix64 state and returns the next pseudo-random number.
+/// Small, dependency-free, and well distributed even from a zero seed.
+pub(crate) fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Helper to advance an Int seed in place and return the raw random output.
+fn advance_seed(seed: &mut AgoType, func: &str) -> u64 {
+    match seed {
+        AgoType::Int(n) => {
+            let mut state = *n as u64;
+            let out = splitmix64(&mut state);
+            *n = state as AgoInt;
+            out
+        }
+        other => panic!("{} expects an Int seed, but got {:?}", func, other),
+    }
+}
+
+/// Returns a pseudo-random non-negative Int and advances `seed` in place, so
+/// the same starting seed always reproduces the same sequence.
+pub fn fortuna(seed: &mut AgoType) -> AgoType {
+    AgoType::Int(advance_seed(seed, "fortuna") as AgoInt)
+}
+
+/// Returns a pseudo-random Int in the inclusive range `low..high` and
+/// advances `seed` in place.
+pub fn fortuna_intra(seed: &mut AgoType, low: &AgoType, high: &AgoType) -> AgoType {
+    let (low, high) = match (low, high) {
+        (AgoType::Int(l), AgoType::Int(h)) if l <= h => (*l, *h),
+        _ => panic!(
+            "fortuna_intra expects Int bounds with low <= high, but got {:?} and {:?}",
+            low, high
+        ),
+    };
+    // Computed in u128 so the widest possible range cannot overflow
+    let span = (high.wrapping_sub(low) as u128).wrapping_add(1);
+    let raw = advance_seed(seed, "fortuna_intra") as u128;
+    let offset = if span == 0 { raw } else { raw % span };
+    AgoType::Int(low.wrapping_add(offset as AgoInt))
+}
+
+/// Calls a lambda with the elements of a list as its arguments.
+pub fn applica(f: &AgoLambda, args: &AgoType) -> AgoType {
+    match args {
+        AgoType::ListAny(args) => f(args),
+        AgoType::IntList(_)
+        | AgoType::FloatList(_)
+        | AgoType::BoolList(_)
+        | AgoType::StringList(_) => f(&into_iter(args).collect::<Vec<_>>()),
+        other => panic!("applica expects a list of arguments, but got {:?}", other),
+    }
+}
+
+/// Partially applies a lambda: the returned lambda calls `f` with the
+/// `bound` arguments followed by its own. The closure owns the bound values.
+pub fn applica_partim(f: AgoLambda, bound: AgoListAny) -> AgoLambda {
+    Rc::new(move |args: &[AgoType]| {
+        let mut all = bound.clone();
+        all.extend_from_slice(args);
+        f(&all)
+    })
+}
+
+/// Helper to write a canonical cache key for a value. AgoType can't be a
+/// HashMap key (Floats aren't `Eq`), so equal values are encoded as equal
+/// Strings instead; Struct entries are sorted so key order doesn't matter.
+fn write_memo_key(val: &AgoType, out: &mut String) {
+    match val {
+        AgoType::Struct(map) => {
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_by(|a, b| a.0.cmp(b.0));
+            out.push_str("Struct{");
+            for (key, value) in entries {
+                let _ = write!(out, "{:?}:", key);
+                write_memo_key(value, out);
+                out.push(',');
+            }
+            out.push('}');
+        }
+        AgoType::ListAny(items) => {
+            out.push_str("ListAny[");
+            for item in items {
+                write_memo_key(item, out);
+                out.push(',');
+            }
+            out.push(']');
+        }
+        other => {
+            let _ = write!(out, "{:?}", other);
+        }
+    }
+}
+
+/// Wraps a lambda so results are cached per distinct argument list. `f`
+/// should be pure; each argument list runs it at most once. The cache is not
+/// borrowed while `f` runs, so recursive calls through the memoized lambda
+/// are fine.
+pub fn memoiza(f: AgoLambda) -> AgoLambda {
+    let cache: RefCell<HashMap<String, AgoType>> = RefCell::new(HashMap::new());
+    Rc::new(move |args: &[AgoType]| {
+        let mut key = String::new();
+        for arg in args {
+            write_memo_key(arg, &mut key);
+            key.push(';');
+        }
+        if let Some(hit) = cache.borrow().get(&key) {
+            return hit.clone();
+        }
+        let result = f(args);
+        cache.borrow_mut().insert(key, result.clone());
+        result
+    })
+}
+
+/// Composes two lambdas: the result computes `f(g(x))`. If `f` can't handle
+/// what `g` returns, `f`'s own panic propagates.
+pub fn compositio(f: AgoLambda, g: AgoLambda) -> AgoLambda {
+    Rc::new(move |args: &[AgoType]| f(&[g(args)]))
+}
+
 /// Exits the program with the given exit code.
 /// Name ends in -i (returns null/inanis - never returns)
 pub fn exei(code: &AgoType) -> AgoType {
@@ -86,7 +1395,44 @@ pub fn exei(code: &AgoType) -> AgoType {
 }
 
 /// Checks equality of two values.
+/// Floats follow IEEE 754, as Rust's `==` does: `-0.0` equals `0.0`, but NaN
+/// is not equal to anything, including itself. Use `aequalam_strictum` when
+/// equality must be reflexive.
 /// Name ends in -am (returns bool)
 pub fn aequalam(left: &AgoType, right: &AgoType) -> AgoType {
     AgoType::Bool(left == right)
 }
+
+/// Helper for `aequalam_strictum`: structural equality comparing Floats by
+/// their bit patterns, recursing into lists and Structs.
+fn strict_eq(left: &AgoType, right: &AgoType) -> bool {
+    match (left, right) {
+        (AgoType::Float(a), AgoType::Float(b)) => a.to_bits() == b.to_bits(),
+        (AgoType::FloatList(a), AgoType::FloatList(b)) => {
+            a.len() == b.len() && a.iter().zip(b).all(|(x, y)| x.to_bits() == y.to_bits())
+        }
+        (AgoType::ListAny(a), AgoType::ListAny(b)) => {
+            a.len() == b.len() && a.iter().zip(b).all(|(x, y)| strict_eq(x, y))
+        }
+        (AgoType::Struct(a), AgoType::Struct(b)) => {
+            a.len() == b.len()
+                && a.iter()
+                    .all(|(k, v)| b.get(k).is_some_and(|other| strict_eq(v, other)))
+        }
+        _ => left == right,
+    }
+}
+
+/// Checks equality of two values, comparing Floats bit-for-bit. Unlike
+/// `aequalam`, NaN equals itself, so the relation is reflexive (useful for
+/// dedup); in exchange `-0.0` and `0.0` are different.
+/// Name ends in -am (returns bool)
+pub fn aequalam_strictum(left: &AgoType, right: &AgoType) -> AgoType {
+    AgoType::Bool(strict_eq(left, right))
+}
+
+/// Misspelled alias of `aequalam`, kept so older generated code still links.
+#[deprecated(note = "use `aequalam`; the -am ending marks a Bool return")]
+pub fn aequalem(left: &AgoType, right: &AgoType) -> AgoType {
+    aequalam(left, right)
+}
@@ -1,4 +1,5 @@
 use crate::types::AgoType;
+use std::io::{BufRead, BufReader};
 
 /// Creates a standard Rust iterator for any iterable AgoType.
 ///
@@ -35,3 +36,22 @@ pub fn into_iter(iterable: &AgoType) -> Box<dyn Iterator<Item = AgoType> + '_> {
         }
     }
 }
+
+/// Lazily yields each line of a file as a String, without its line ending.
+///
+/// The file is read through a buffered reader, so a `for line in file` loop
+/// never holds the whole file in memory. Panics if the file cannot be opened;
+/// a read error partway through (e.g. invalid UTF-8) also panics rather than
+/// silently ending the loop early.
+pub fn lineae_iter(path: &AgoType) -> Box<dyn Iterator<Item = AgoType>> {
+    let path = match path {
+        AgoType::String(p) => p.clone(),
+        other => panic!("lineae_iter expects a String path, but got {:?}", other),
+    };
+    let file = std::fs::File::open(&path)
+        .unwrap_or_else(|e| panic!("Failed to open file '{}': {}", path, e));
+    Box::new(BufReader::new(file).lines().map(move |line| match line {
+        Ok(line) => AgoType::String(line),
+        Err(e) => panic!("Failed to read from file '{}': {}", path, e),
+    }))
+}
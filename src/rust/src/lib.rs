@@ -6,13 +6,33 @@ pub mod operators;
 pub mod types;
 
 // Re-export everything for easy importing
-pub use collections::{get, inseri, removium, set, validate_list_type};
-pub use functions::{aequalam, apertu, audies, dici, exei, species, scribi};
-pub use iterators::into_iter;
+pub use collections::{
+    adnota, caput, cauda, claves_ordinatae, coerce_list, congrega, contrahe, cum, cumula,
+    divide_per, ex_tabula, explica, extrema, filtra_claves, frequentia, fusio_profunda, get,
+    get_auto, in_ordine, inseri, intercala, itera_dum, maxime, maximum_per, minime, minimum_per,
+    misce, numera_per, numera_vera, omitte_claves, omnis, ordina_per, paria_ordinata, per_partes,
+    permuta, plica, profunda_get, profunda_set, quaere_binarium, removium, repete, rotatio,
+    sequentia, set, set_auto, singulum, tabula, transforma_valores, transpone, ullus, ultimum,
+    validate_list_type, valores_ordinati, AgoCollector,
+};
+pub use functions::{
+    abs_differentia, ad_binarium, ad_chr, ad_csv, ad_hexadecimale, ad_romanum, ad_textum, aequalam,
+    aequalam_strictum, ambiens, apertu, applica, applica_partim, args_from, argumenta, audies,
+    bool_ad_float, ceil_list, compositio, cosinus, desinit, deviatio, deviatio_sample, dici, est,
+    est_inanis, est_numerus, ex_binarium, ex_chorda_basi, ex_chr, ex_csv, ex_hexadecimale,
+    ex_json_lineae, ex_romanum, exei, exponentialis, finde_n, floor_list, formatta, fortuna,
+    fortuna_intra, homogeneum, imple_dextra, imple_sinistra, incipit, indenta, lineae, logarithmus,
+    logarithmus_base, longitudo, longitudo_bitorum, maximus_divisor, media, mediana, memoiza,
+    minimum_multiplum, numerus_bitorum, par, potentia_modularis, replica, rotundum_ad, round_list,
+    scribe, scribi, signum, sinus, species, sub_chorda, tangens, tempus, typus_communis, vacuum,
+    verba,
+};
+pub use iterators::{into_iter, lineae_iter};
 pub use operators::{
-    add, and, bitwise_and, bitwise_or, bitwise_xor, contains, divide, elvis, greater_equal,
-    greater_than, less_equal, less_than, modulo, multiply, not, or, slice, sliceto, subtract,
-    unary_minus, unary_plus,
+    add, and, aut, bitwise_and, bitwise_or, bitwise_xor, compare, contains, continet_nan,
+    continet_neglecto_casu, continet_structuram, divide, elvis, greater_equal, greater_than,
+    implicat, inter, less_equal, less_than, modulo, multiply, not, or, slice, sliceto, subtract,
+    unary_minus, unary_plus, vel_inanis,
 };
 pub use types::{AgoBool, AgoFloat, AgoInt, AgoLambda, AgoRange, AgoString, AgoType, TargetType};
 // Note: casting is done via AgoType::as_type(TargetType::X)
@@ -1,4 +1,5 @@
-use crate::types::{AgoRange, AgoType};
+use crate::types::{AgoRange, AgoType, TargetType};
+use std::cmp::Ordering;
 
 // --- Operator Functions ---
 
@@ -32,19 +33,43 @@ macro_rules! comparison_op {
     ($name:ident, $op:tt) => {
         #[inline]
         pub fn $name(left: &AgoType, right: &AgoType) -> AgoType {
-            let result = match (left, right) {
-                (AgoType::Float(a), AgoType::Float(b)) => a $op b,
-                (AgoType::Float(a), AgoType::Int(b)) => a $op &(*b as f64),
-                (AgoType::Int(a), AgoType::Float(b)) => &(*a as f64) $op b,
-                (AgoType::Int(a), AgoType::Int(b)) => a $op b,
-                (AgoType::String(a), AgoType::String(b)) => a $op b,
-                _ => panic!("Cannot perform comparison on {:?} and {:?}", left, right),
+            let result = match compare(left, right) {
+                Some(ord) => ord $op Ordering::Equal,
+                // Only NaN leaves two numbers unordered; IEEE 754 says false.
+                None if is_number(left) && is_number(right) => false,
+                None => panic!("Cannot perform comparison on {:?} and {:?}", left, right),
             };
             AgoType::Bool(result)
         }
     };
 }
 
+/// Helper to check for the numeric variants.
+fn is_number(val: &AgoType) -> bool {
+    matches!(val, AgoType::Int(_) | AgoType::Float(_))
+}
+
+/// Orders two values using the same rules as the comparison operators:
+/// numbers compare numerically (promoting to Float if mixed), Strings compare
+/// lexicographically, and Bools order `false < true`. Null is below every
+/// other value (and equal to itself), so lists containing Nulls can be sorted.
+/// Any other cross-type pair is not comparable and returns None, as is NaN.
+/// The comparison operators are built on this function.
+pub fn compare(left: &AgoType, right: &AgoType) -> Option<Ordering> {
+    match (left, right) {
+        (AgoType::Float(a), AgoType::Float(b)) => a.partial_cmp(b),
+        (AgoType::Float(a), AgoType::Int(b)) => a.partial_cmp(&(*b as f64)),
+        (AgoType::Int(a), AgoType::Float(b)) => (*a as f64).partial_cmp(b),
+        (AgoType::Int(a), AgoType::Int(b)) => Some(a.cmp(b)),
+        (AgoType::String(a), AgoType::String(b)) => Some(a.cmp(b)),
+        (AgoType::Bool(a), AgoType::Bool(b)) => Some(a.cmp(b)),
+        (AgoType::Null, AgoType::Null) => Some(Ordering::Equal),
+        (AgoType::Null, _) => Some(Ordering::Less),
+        (_, AgoType::Null) => Some(Ordering::Greater),
+        _ => None,
+    }
+}
+
 /// Implements the '..' operator for inclusive ranges.
 pub fn slice(left: &AgoType, right: &AgoType) -> AgoType {
     match (left, right) {
@@ -149,6 +174,25 @@ pub fn or(left: &AgoType, right: &AgoType) -> AgoType {
     }
 }
 
+/// Implements the logical 'xor' operator. Panics if inputs are not booleans.
+pub fn aut(left: &AgoType, right: &AgoType) -> AgoType {
+    match (left, right) {
+        (AgoType::Bool(a), AgoType::Bool(b)) => AgoType::Bool(a != b),
+        _ => panic!("Cannot perform logical 'xor' on {:?} and {:?}", left, right),
+    }
+}
+
+/// Implements logical implication (`!a || b`). Panics if inputs are not booleans.
+pub fn implicat(left: &AgoType, right: &AgoType) -> AgoType {
+    match (left, right) {
+        (AgoType::Bool(a), AgoType::Bool(b)) => AgoType::Bool(!a || *b),
+        _ => panic!(
+            "Cannot perform logical implication on {:?} and {:?}",
+            left, right
+        ),
+    }
+}
+
 /// Implements the unary 'not' operator.
 pub fn not(val: &AgoType) -> AgoType {
     match val {
@@ -175,8 +219,20 @@ pub fn unary_plus(val: &AgoType) -> AgoType {
 }
 
 /// Implements the 'in' operator.
+/// Float membership follows IEEE equality, so searching for NaN is always
+/// false even when the list holds a NaN; use `continet_nan` for that check.
+/// Searching a typed list for Null is false (a typed list can't hold Null),
+/// while a ListAny is searched normally and may contain Null.
 pub fn contains(haystack: &AgoType, needle: &AgoType) -> AgoType {
     let result = match haystack {
+        AgoType::IntList(_)
+        | AgoType::FloatList(_)
+        | AgoType::BoolList(_)
+        | AgoType::StringList(_)
+            if matches!(needle, AgoType::Null) =>
+        {
+            false
+        }
         AgoType::String(h) => {
             if let AgoType::String(n) = needle {
                 h.contains(n)
@@ -222,6 +278,49 @@ pub fn contains(haystack: &AgoType, needle: &AgoType) -> AgoType {
     AgoType::Bool(result)
 }
 
+/// Case-insensitive substring search. Both Strings are lowercased with full
+/// Unicode rules before comparing; `contains` stays strict.
+pub fn continet_neglecto_casu(haystack: &AgoType, needle: &AgoType) -> AgoType {
+    match (haystack, needle) {
+        (AgoType::String(h), AgoType::String(n)) => {
+            AgoType::Bool(h.to_lowercase().contains(&n.to_lowercase()))
+        }
+        _ => panic!(
+            "Can only search for a String in a String, not {:?} in {:?}",
+            needle, haystack
+        ),
+    }
+}
+
+/// Checks whether a FloatList (or the Floats in a ListAny) holds any NaN.
+pub fn continet_nan(haystack: &AgoType) -> AgoType {
+    let result = match haystack {
+        AgoType::FloatList(h) => h.iter().any(|f| f.is_nan()),
+        AgoType::ListAny(h) => h
+            .iter()
+            .any(|item| matches!(item, AgoType::Float(f) if f.is_nan())),
+        _ => panic!(
+            "continet_nan expects a FloatList or ListAny, not {:?}",
+            haystack
+        ),
+    };
+    AgoType::Bool(result)
+}
+
+/// Checks whether every key/value pair of the `part` Struct also appears in
+/// `whole`. Values are compared shallowly with `==`.
+pub fn continet_structuram(whole: &AgoType, part: &AgoType) -> AgoType {
+    match (whole, part) {
+        (AgoType::Struct(w), AgoType::Struct(p)) => {
+            AgoType::Bool(p.iter().all(|(key, value)| w.get(key) == Some(value)))
+        }
+        _ => panic!(
+            "continet_structuram expects two Structs, not {:?} and {:?}",
+            whole, part
+        ),
+    }
+}
+
 /// Implements the null-coalescing '?:' operator.
 /// Returns the left value if it is not Null. Otherwise, returns the right value.
 /// Panics if both values are Null.
@@ -234,3 +333,24 @@ pub fn elvis(left: &AgoType, right: &AgoType) -> AgoType {
     }
     panic!("Cannot coalesce two null values with '?:' operator");
 }
+
+/// Non-panicking null coalescing: returns the left value if it is not Null,
+/// otherwise the right value, which may itself be Null.
+pub fn vel_inanis(left: &AgoType, right: &AgoType) -> AgoType {
+    if matches!(left, AgoType::Null) {
+        right.clone()
+    } else {
+        left.clone()
+    }
+}
+
+/// Implements the ternary operator. Returns `if_true` when `cond` is truthy
+/// (per the Bool cast) and `if_false` otherwise. Both branches are already
+/// evaluated, matching Ago's strict runtime.
+pub fn inter(cond: &AgoType, if_true: &AgoType, if_false: &AgoType) -> AgoType {
+    if cond.as_type(TargetType::Bool) == AgoType::Bool(true) {
+        if_true.clone()
+    } else {
+        if_false.clone()
+    }
+}
@@ -19,6 +19,27 @@ pub enum AgoType {
     Null, // Representing Ago's 'inanis'
 }
 
+impl AgoType {
+    /// Returns the `TargetType` matching this value's variant, so a value can
+    /// be cast back to its own type or compared by variant.
+    pub fn target_type(&self) -> TargetType {
+        match self {
+            AgoType::Int(_) => TargetType::Int,
+            AgoType::Float(_) => TargetType::Float,
+            AgoType::Bool(_) => TargetType::Bool,
+            AgoType::String(_) => TargetType::String,
+            AgoType::IntList(_) => TargetType::IntList,
+            AgoType::FloatList(_) => TargetType::FloatList,
+            AgoType::BoolList(_) => TargetType::BoolList,
+            AgoType::StringList(_) => TargetType::StringList,
+            AgoType::Struct(_) => TargetType::Struct,
+            AgoType::ListAny(_) => TargetType::ListAny,
+            AgoType::Range(_) => TargetType::Range,
+            AgoType::Null => TargetType::Null,
+        }
+    }
+}
+
 // Type aliases for clarity
 pub type AgoInt = i128;
 pub type AgoFloat = f64;
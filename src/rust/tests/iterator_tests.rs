@@ -1,4 +1,4 @@
-use ago_stdlib::iterators::into_iter;
+use ago_stdlib::iterators::{into_iter, lineae_iter};
 use ago_stdlib::types::{AgoRange, AgoType};
 
 #[test]
@@ -92,3 +92,23 @@ fn test_iter_non_iterable() {
     let mut iter = into_iter(&val);
     assert_eq!(iter.next(), None);
 }
+
+#[test]
+fn test_lineae_iter() {
+    let path = std::env::temp_dir().join(format!("ago_lineae_iter_{}.txt", std::process::id()));
+    let content: String = (0..1000).map(|i| format!("line {}\r\n", i)).collect();
+    std::fs::write(&path, content).unwrap();
+
+    let path_str = AgoType::String(path.to_string_lossy().into_owned());
+    let mut lines = lineae_iter(&path_str);
+    assert_eq!(lines.next(), Some(AgoType::String("line 0".to_string())));
+    assert_eq!(lines.count(), 999);
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Failed to open file")]
+fn test_lineae_iter_missing_file() {
+    let _ = lineae_iter(&AgoType::String("/nonexistent/ago/file.txt".to_string()));
+}
@@ -1,14 +1,34 @@
 //! Integration tests for the ago_stdlib crate.
 
-use ago_stdlib::collections::{get, inseri, removium, set};
-use ago_stdlib::functions::{aequalam, species};
+use ago_stdlib::collections::{
+    adnota, caput, cauda, claves_ordinatae, coerce_list, congrega, contrahe, cum, cumula,
+    divide_per, ex_tabula, explica, extrema, filtra_claves, frequentia, fusio_profunda, get,
+    get_auto, in_ordine, inseri, intercala, itera_dum, maxime, maximum_per, minime, minimum_per,
+    misce, numera_per, numera_vera, omitte_claves, omnis, ordina_per, paria_ordinata, per_partes,
+    permuta, plica, profunda_get, profunda_set, quaere_binarium, removium, repete, rotatio,
+    sequentia, set, set_auto, singulum, tabula, transforma_valores, transpone, ullus, ultimum,
+    valores_ordinati, AgoCollector,
+};
+use ago_stdlib::functions::{
+    abs_differentia, ad_binarium, ad_chr, ad_csv, ad_hexadecimale, ad_romanum, ad_textum, aequalam,
+    aequalam_strictum, ambiens, applica, applica_partim, args_from, bool_ad_float, ceil_list,
+    compositio, cosinus, desinit, deviatio, deviatio_sample, dici_to, est, est_inanis, est_numerus,
+    ex_binarium, ex_chorda_basi, ex_chr, ex_csv, ex_hexadecimale, ex_json_lineae, ex_romanum,
+    exponentialis, finde_n, floor_list, formatta, fortuna, fortuna_intra, homogeneum, imple_dextra,
+    imple_sinistra, incipit, indenta, lineae, logarithmus, logarithmus_base, longitudo,
+    longitudo_bitorum, maximus_divisor, media, mediana, memoiza, minimum_multiplum,
+    numerus_bitorum, par, potentia_modularis, replica, rotundum_ad, round_list, scribe_to, signum,
+    sinus, species, sub_chorda, tangens, tempus, typus_communis, vacuum, verba,
+};
 use ago_stdlib::operators::{
-    add, and, bitwise_and, bitwise_or, bitwise_xor, contains, divide, elvis, greater_equal,
-    greater_than, less_equal, less_than, modulo, multiply, not, or, slice, sliceto, subtract,
-    unary_minus, unary_plus,
+    add, and, aut, bitwise_and, bitwise_or, bitwise_xor, compare, contains, continet_nan,
+    continet_neglecto_casu, continet_structuram, divide, elvis, greater_equal, greater_than,
+    implicat, inter, less_equal, less_than, modulo, multiply, not, or, slice, sliceto, subtract,
+    unary_minus, unary_plus, vel_inanis,
 };
-use ago_stdlib::types::{AgoRange, AgoType, TargetType};
+use ago_stdlib::types::{AgoLambda, AgoRange, AgoType, TargetType};
 use std::collections::HashMap;
+use std::rc::Rc;
 
 // --- Helpers ---
 
@@ -27,6 +47,67 @@ fn sample_any_list() -> AgoType {
     ])
 }
 
+fn person(name: &str, age: i128) -> AgoType {
+    let mut map = HashMap::new();
+    map.insert("name".to_string(), AgoType::String(name.to_string()));
+    map.insert("age".to_string(), AgoType::Int(age));
+    AgoType::Struct(map)
+}
+
+fn by_age() -> AgoLambda {
+    Rc::new(|args: &[AgoType]| get(&args[0], &AgoType::String("age".to_string())))
+}
+
+fn char_count() -> AgoLambda {
+    Rc::new(|args: &[AgoType]| match &args[0] {
+        AgoType::String(s) => AgoType::Int(s.chars().count() as i128),
+        other => panic!("char_count expects a String, got {:?}", other),
+    })
+}
+
+fn identity() -> AgoLambda {
+    Rc::new(|args: &[AgoType]| args[0].clone())
+}
+
+fn assert_float_near(actual: AgoType, expected: f64) {
+    match actual {
+        AgoType::Float(f) => assert!(
+            (f - expected).abs() < 1e-9,
+            "expected {} but got {}",
+            expected,
+            f
+        ),
+        other => panic!("Expected a Float, got {:?}", other),
+    }
+}
+
+fn is_even() -> AgoLambda {
+    Rc::new(|args: &[AgoType]| match &args[0] {
+        AgoType::Int(n) => AgoType::Bool(n % 2 == 0),
+        other => panic!("is_even expects an Int, got {:?}", other),
+    })
+}
+
+/// {"users": [ {"name": "Ada", "tags": ["x", "y"]} ]}
+fn nested_document() -> AgoType {
+    let mut user = HashMap::new();
+    user.insert("name".to_string(), AgoType::String("Ada".to_string()));
+    user.insert(
+        "tags".to_string(),
+        AgoType::StringList(vec!["x".to_string(), "y".to_string()]),
+    );
+    let mut root = HashMap::new();
+    root.insert(
+        "users".to_string(),
+        AgoType::ListAny(vec![AgoType::Struct(user)]),
+    );
+    AgoType::Struct(root)
+}
+
+fn path(steps: Vec<AgoType>) -> AgoType {
+    AgoType::ListAny(steps)
+}
+
 // --- Test Cases ---
 
 #[test]
@@ -82,6 +163,23 @@ fn test_species() {
     );
 }
 
+#[test]
+fn test_homogeneum_typus_communis() {
+    let ints = AgoType::ListAny(vec![AgoType::Int(1), AgoType::Int(2)]);
+    assert_eq!(homogeneum(&ints), AgoType::Bool(true));
+    assert_eq!(typus_communis(&ints), AgoType::String("Int".to_string()));
+
+    assert_eq!(homogeneum(&sample_any_list()), AgoType::Bool(false));
+    assert_eq!(
+        typus_communis(&sample_any_list()),
+        AgoType::String("mixtum".to_string())
+    );
+
+    let empty = AgoType::ListAny(vec![]);
+    assert_eq!(homogeneum(&empty), AgoType::Bool(true));
+    assert_eq!(typus_communis(&empty), AgoType::Null);
+}
+
 #[test]
 fn test_as_type_primitive_conversions() {
     // Int
@@ -269,7 +367,7 @@ fn test_get_wrong_index_type_for_list() {
 fn test_set() {
     // List
     let mut list = AgoType::IntList(vec![10, 20, 30]);
-    set(&mut list, &AgoType::Int(1), AgoType::Int(99));
+    set(&mut list, &AgoType::Int(1), &AgoType::Int(99));
     assert_eq!(list, AgoType::IntList(vec![10, 99, 30]));
 
     // Struct (update existing)
@@ -277,7 +375,7 @@ fn test_set() {
     set(
         &mut s1,
         &AgoType::String("b".to_string()),
-        AgoType::String("world".to_string()),
+        &AgoType::String("world".to_string()),
     );
     assert_eq!(
         get(&s1, &AgoType::String("b".to_string())),
@@ -289,7 +387,7 @@ fn test_set() {
     set(
         &mut s2,
         &AgoType::String("c".to_string()),
-        AgoType::Int(100),
+        &AgoType::Int(100),
     );
     assert_eq!(
         get(&s2, &AgoType::String("c".to_string())),
@@ -301,7 +399,7 @@ fn test_set() {
 #[should_panic]
 fn test_set_list_wrong_value_type() {
     let mut list = AgoType::IntList(vec![10]);
-    set(&mut list, &AgoType::Int(0), AgoType::Float(1.0));
+    set(&mut list, &AgoType::Int(0), &AgoType::Float(1.0));
 }
 
 #[test]
@@ -341,6 +439,79 @@ fn test_inseri_list_wrong_value_type() {
     inseri(&mut list, &AgoType::Int(0), &AgoType::Int(123));
 }
 
+#[test]
+fn test_get_set_auto_int_key() {
+    let mut s = AgoType::Struct(HashMap::new());
+    set_auto(
+        &mut s,
+        &AgoType::Int(7),
+        &AgoType::String("seven".to_string()),
+    );
+    assert_eq!(
+        get_auto(&s, &AgoType::Int(7)),
+        AgoType::String("seven".to_string())
+    );
+    assert_eq!(
+        get(&s, &AgoType::String("7".to_string())),
+        AgoType::String("seven".to_string())
+    );
+
+    // Lists keep their usual Int indexing.
+    let mut list = AgoType::IntList(vec![1, 2]);
+    set_auto(&mut list, &AgoType::Int(1), &AgoType::Int(5));
+    assert_eq!(get_auto(&list, &AgoType::Int(1)), AgoType::Int(5));
+}
+
+#[test]
+#[should_panic(expected = "use get_auto/set_auto")]
+fn test_set_struct_int_key_suggests_auto() {
+    let mut s = sample_struct();
+    set(&mut s, &AgoType::Int(1), &AgoType::Null);
+}
+
+#[test]
+fn test_inseri_at_len_appends() {
+    let mut list = AgoType::IntList(vec![1, 2]);
+    inseri(&mut list, &AgoType::Int(2), &AgoType::Int(3));
+    assert_eq!(list, AgoType::IntList(vec![1, 2, 3]));
+
+    let mut empty = AgoType::ListAny(vec![]);
+    inseri(&mut empty, &AgoType::Int(0), &AgoType::Null);
+    assert_eq!(empty, AgoType::ListAny(vec![AgoType::Null]));
+}
+
+#[test]
+#[should_panic(expected = "Index out of bounds: 3")]
+fn test_inseri_beyond_len() {
+    let mut list = AgoType::IntList(vec![1, 2]);
+    inseri(&mut list, &AgoType::Int(3), &AgoType::Int(3));
+}
+
+#[test]
+#[should_panic(expected = "Index out of bounds: -1")]
+fn test_inseri_negative_index() {
+    let mut list = AgoType::StringList(vec![]);
+    inseri(
+        &mut list,
+        &AgoType::Int(-1),
+        &AgoType::String("x".to_string()),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Index out of bounds: 0")]
+fn test_removium_empty_list() {
+    let mut list = AgoType::IntList(vec![]);
+    removium(&mut list, &AgoType::Int(0));
+}
+
+#[test]
+#[should_panic(expected = "Index out of bounds: 5")]
+fn test_removium_beyond_len() {
+    let mut list = sample_any_list();
+    removium(&mut list, &AgoType::Int(5));
+}
+
 #[test]
 fn test_removium() {
     // List
@@ -441,213 +612,2657 @@ fn test_aequalam() {
     );
 }
 
-// --- Operator Tests ---
-
 #[test]
-fn test_arithmetic_operators() {
-    // Int, Int
-    assert_eq!(add(&AgoType::Int(5), &AgoType::Int(2)), AgoType::Int(7));
+fn test_divide_per() {
+    let list = AgoType::IntList(vec![1, 2, 3, 4, 5, 6]);
     assert_eq!(
-        subtract(&AgoType::Int(5), &AgoType::Int(2)),
-        AgoType::Int(3)
+        divide_per(&list, &is_even()),
+        AgoType::ListAny(vec![
+            AgoType::IntList(vec![2, 4, 6]),
+            AgoType::IntList(vec![1, 3, 5]),
+        ])
     );
+
+    // Empty input yields two empty lists of the same variant
     assert_eq!(
-        multiply(&AgoType::Int(5), &AgoType::Int(2)),
-        AgoType::Int(10)
+        divide_per(&AgoType::IntList(vec![]), &is_even()),
+        AgoType::ListAny(vec![AgoType::IntList(vec![]), AgoType::IntList(vec![])])
     );
-    assert_eq!(divide(&AgoType::Int(5), &AgoType::Int(2)), AgoType::Int(2));
-    assert_eq!(modulo(&AgoType::Int(5), &AgoType::Int(2)), AgoType::Int(1));
+}
 
-    // Float, Float
+#[test]
+#[should_panic(expected = "predicate must return a Bool")]
+fn test_divide_per_non_bool_predicate() {
+    divide_per(&AgoType::IntList(vec![1]), &identity());
+}
+
+#[test]
+fn test_ordina_per() {
+    let mut people = AgoType::ListAny(vec![
+        person("Cato", 48),
+        person("Livia", 19),
+        person("Brutus", 33),
+        person("Tullia", 19),
+    ]);
+    ordina_per(&mut people, &by_age());
+
+    // Stable: Livia stays ahead of Tullia
     assert_eq!(
-        add(&AgoType::Float(5.0), &AgoType::Float(2.0)),
-        AgoType::Float(7.0)
+        people,
+        AgoType::ListAny(vec![
+            person("Livia", 19),
+            person("Tullia", 19),
+            person("Brutus", 33),
+            person("Cato", 48),
+        ])
     );
+
+    let mut words = AgoType::StringList(vec!["ccc".to_string(), "a".to_string(), "bb".to_string()]);
+    ordina_per(&mut words, &char_count());
     assert_eq!(
-        subtract(&AgoType::Float(5.0), &AgoType::Float(2.0)),
-        AgoType::Float(3.0)
+        words,
+        AgoType::StringList(vec!["a".to_string(), "bb".to_string(), "ccc".to_string()])
     );
+}
+
+#[test]
+#[should_panic(expected = "Cannot order keys")]
+fn test_ordina_per_incomparable_keys() {
+    let mut list = sample_any_list();
+    ordina_per(&mut list, &identity());
+}
+
+#[test]
+fn test_congrega() {
+    let numbers = AgoType::IntList(vec![1, 2, 3, 4, 5]);
+    let groups = congrega(&numbers, &is_even());
     assert_eq!(
-        multiply(&AgoType::Float(5.0), &AgoType::Float(2.0)),
-        AgoType::Float(10.0)
+        get(&groups, &AgoType::String("true".to_string())),
+        AgoType::ListAny(vec![AgoType::Int(2), AgoType::Int(4)])
     );
     assert_eq!(
-        divide(&AgoType::Float(5.0), &AgoType::Float(2.0)),
-        AgoType::Float(2.5)
+        get(&groups, &AgoType::String("false".to_string())),
+        AgoType::ListAny(vec![AgoType::Int(1), AgoType::Int(3), AgoType::Int(5)])
     );
 
-    // Mixed
+    let people = AgoType::ListAny(vec![
+        person("Livia", 19),
+        person("Cato", 48),
+        person("Tullia", 19),
+    ]);
+    let groups = congrega(&people, &by_age());
     assert_eq!(
-        add(&AgoType::Int(5), &AgoType::Float(2.5)),
-        AgoType::Float(7.5)
+        get(&groups, &AgoType::String("19".to_string())),
+        AgoType::ListAny(vec![person("Livia", 19), person("Tullia", 19)])
     );
     assert_eq!(
-        subtract(&AgoType::Float(5.0), &AgoType::Int(2)),
-        AgoType::Float(3.0)
+        get(&groups, &AgoType::String("48".to_string())),
+        AgoType::ListAny(vec![person("Cato", 48)])
     );
+
+    // Struct keys (alone or inside a list) built separately land in one group.
+    let same = AgoType::ListAny((0..10).map(|_| person("Livia", 19)).collect());
+    let wrapped: AgoLambda = Rc::new(|args: &[AgoType]| AgoType::ListAny(vec![args[0].clone()]));
+    for key_fn in [identity(), wrapped] {
+        let AgoType::Struct(groups) = congrega(&same, &key_fn) else {
+            panic!("congrega must return a Struct");
+        };
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups.values().next(), Some(&same));
+    }
 }
 
 #[test]
-fn test_add_concatenation() {
-    // String
-    let s1 = AgoType::String("hello".to_string());
-    let s2 = AgoType::String(" world".to_string());
-    assert_eq!(add(&s1, &s2), AgoType::String("hello world".to_string()));
+fn test_minimum_per_maximum_per() {
+    let words = AgoType::StringList(vec![
+        "ab".to_string(),
+        "abcd".to_string(),
+        "wxyz".to_string(),
+        "a".to_string(),
+    ]);
+    // Ties go to the first element encountered
+    assert_eq!(
+        maximum_per(&words, &char_count()),
+        AgoType::String("abcd".to_string())
+    );
+    assert_eq!(
+        minimum_per(&words, &char_count()),
+        AgoType::String("a".to_string())
+    );
+
+    let people = AgoType::ListAny(vec![
+        person("Livia", 19),
+        person("Cato", 48),
+        person("Brutus", 33),
+    ]);
+    assert_eq!(maximum_per(&people, &by_age()), person("Cato", 48));
+    assert_eq!(minimum_per(&people, &by_age()), person("Livia", 19));
+}
 
-    // List
-    let l1 = AgoType::IntList(vec![1, 2]);
-    let l2 = AgoType::IntList(vec![3, 4]);
-    assert_eq!(add(&l1, &l2), AgoType::IntList(vec![1, 2, 3, 4]));
+#[test]
+#[should_panic(expected = "Cannot reduce an empty list")]
+fn test_maximum_per_empty() {
+    maximum_per(&AgoType::StringList(vec![]), &char_count());
 }
 
 #[test]
-#[should_panic]
-fn test_arithmetic_panic() {
-    add(&AgoType::Int(5), &AgoType::String("hello".to_string()));
+fn test_omnis_ullus() {
+    let empty = AgoType::IntList(vec![]);
+    assert_eq!(omnis(&empty, &is_even()), AgoType::Bool(true));
+    assert_eq!(ullus(&empty, &is_even()), AgoType::Bool(false));
+
+    let evens = AgoType::IntList(vec![2, 4, 6]);
+    assert_eq!(omnis(&evens, &is_even()), AgoType::Bool(true));
+    assert_eq!(ullus(&evens, &is_even()), AgoType::Bool(true));
+
+    let odds = AgoType::IntList(vec![1, 3, 5]);
+    assert_eq!(omnis(&odds, &is_even()), AgoType::Bool(false));
+    assert_eq!(ullus(&odds, &is_even()), AgoType::Bool(false));
+
+    let mixed = AgoType::IntList(vec![1, 2, 3]);
+    assert_eq!(omnis(&mixed, &is_even()), AgoType::Bool(false));
+    assert_eq!(ullus(&mixed, &is_even()), AgoType::Bool(true));
 }
 
 #[test]
-fn test_comparison_operators() {
-    // Numeric
-    assert_eq!(
-        greater_than(&AgoType::Int(5), &AgoType::Int(2)),
-        AgoType::Bool(true)
-    );
+fn test_omnis_short_circuits() {
+    // The String element would make is_even panic if it were ever reached
+    let list = AgoType::ListAny(vec![AgoType::Int(1), AgoType::String("x".to_string())]);
+    assert_eq!(omnis(&list, &is_even()), AgoType::Bool(false));
+    let list = AgoType::ListAny(vec![AgoType::Int(2), AgoType::String("x".to_string())]);
+    assert_eq!(ullus(&list, &is_even()), AgoType::Bool(true));
+}
+
+#[test]
+#[should_panic(expected = "predicate must return a Bool")]
+fn test_ullus_non_bool_predicate() {
+    ullus(&AgoType::IntList(vec![1]), &identity());
+}
+
+#[test]
+fn test_numera_per() {
+    let numbers = AgoType::IntList(vec![1, 2, 3, 4, 6]);
+    assert_eq!(numera_per(&numbers, &is_even()), AgoType::Int(3));
+
+    let words = AgoType::StringList(vec!["".to_string(), "salve".to_string(), "".to_string()]);
+    let is_empty: AgoLambda =
+        Rc::new(|args: &[AgoType]| AgoType::Bool(args[0] == AgoType::String("".to_string())));
+    assert_eq!(numera_per(&words, &is_empty), AgoType::Int(2));
     assert_eq!(
-        less_than(&AgoType::Float(5.0), &AgoType::Int(2)),
-        AgoType::Bool(false)
+        numera_per(&AgoType::StringList(vec![]), &is_empty),
+        AgoType::Int(0)
     );
+}
+
+#[test]
+fn test_per_partes() {
+    let list = AgoType::IntList(vec![1, 2, 3, 4, 5, 6]);
     assert_eq!(
-        greater_equal(&AgoType::Int(5), &AgoType::Float(5.0)),
-        AgoType::Bool(true)
+        per_partes(&list, &AgoType::Int(2)),
+        AgoType::ListAny(vec![
+            AgoType::IntList(vec![1, 2]),
+            AgoType::IntList(vec![3, 4]),
+            AgoType::IntList(vec![5, 6]),
+        ])
     );
+
+    // Remainder chunk
     assert_eq!(
-        less_equal(&AgoType::Int(5), &AgoType::Int(5)),
-        AgoType::Bool(true)
+        per_partes(&list, &AgoType::Int(4)),
+        AgoType::ListAny(vec![
+            AgoType::IntList(vec![1, 2, 3, 4]),
+            AgoType::IntList(vec![5, 6]),
+        ])
     );
 
-    // String
+    // Size larger than the list
     assert_eq!(
-        greater_than(
-            &AgoType::String("b".to_string()),
-            &AgoType::String("a".to_string())
-        ),
-        AgoType::Bool(true)
+        per_partes(&list, &AgoType::Int(10)),
+        AgoType::ListAny(vec![list.clone()])
     );
+
+    // Sizes beyond usize are not truncated
+    for size in [1 << 64, (1 << 64) + 1] {
+        assert_eq!(
+            per_partes(&list, &AgoType::Int(size)),
+            AgoType::ListAny(vec![list.clone()])
+        );
+    }
+}
+
+#[test]
+#[should_panic(expected = "Chunk size must be a positive Int")]
+fn test_per_partes_zero_size() {
+    per_partes(&AgoType::IntList(vec![1]), &AgoType::Int(0));
+}
+
+#[test]
+fn test_contrahe() {
+    let ints = AgoType::ListAny(vec![AgoType::Int(1), AgoType::Int(2)]);
+    assert_eq!(contrahe(&ints), AgoType::IntList(vec![1, 2]));
+
+    // Int and Float are not merged: the list stays mixed
+    let numbers = AgoType::ListAny(vec![AgoType::Int(1), AgoType::Float(2.0)]);
+    assert_eq!(contrahe(&numbers), numbers);
+
+    assert_eq!(contrahe(&sample_any_list()), sample_any_list());
     assert_eq!(
-        less_than(
-            &AgoType::String("b".to_string()),
-            &AgoType::String("a".to_string())
-        ),
-        AgoType::Bool(false)
+        contrahe(&AgoType::ListAny(vec![])),
+        AgoType::ListAny(vec![])
     );
 }
 
 #[test]
-fn test_logical_operators() {
+fn test_coerce_list() {
+    let numbers = AgoType::ListAny(vec![AgoType::Int(1), AgoType::Float(2.5)]);
     assert_eq!(
-        and(&AgoType::Bool(true), &AgoType::Bool(false)),
-        AgoType::Bool(false)
+        coerce_list(&numbers, "float"),
+        AgoType::FloatList(vec![1.0, 2.5])
     );
+
+    let words = AgoType::ListAny(vec![AgoType::String("a".to_string())]);
     assert_eq!(
-        and(&AgoType::Bool(true), &AgoType::Bool(true)),
-        AgoType::Bool(true)
+        coerce_list(&words, "string"),
+        AgoType::StringList(vec!["a".to_string()])
     );
+}
+
+#[test]
+#[should_panic(expected = "List element 1 has type 'string', but list expects 'float' elements")]
+fn test_coerce_list_bad_element() {
+    let list = AgoType::ListAny(vec![AgoType::Float(1.0), AgoType::String("x".to_string())]);
+    coerce_list(&list, "float");
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_aequalem_alias() {
+    use ago_stdlib::functions::aequalem;
     assert_eq!(
-        or(&AgoType::Bool(true), &AgoType::Bool(false)),
-        AgoType::Bool(true)
+        aequalem(&AgoType::Int(5), &AgoType::Int(5)),
+        aequalam(&AgoType::Int(5), &AgoType::Int(5))
     );
     assert_eq!(
-        or(&AgoType::Bool(false), &AgoType::Bool(false)),
+        aequalem(&AgoType::Int(5), &AgoType::Float(5.0)),
         AgoType::Bool(false)
     );
-    assert_eq!(not(&AgoType::Bool(true)), AgoType::Bool(false));
-    assert_eq!(not(&AgoType::Bool(false)), AgoType::Bool(true));
 }
 
 #[test]
-#[should_panic]
-fn test_logical_panic() {
-    and(&AgoType::Bool(true), &AgoType::Int(1));
+fn test_dici_scribe_newlines() {
+    let mut out: Vec<u8> = Vec::new();
+    scribe_to(&mut out, &AgoType::String("salve".to_string()));
+    scribe_to(&mut out, &AgoType::Int(1));
+    assert_eq!(String::from_utf8(out).unwrap(), "salve1");
+
+    let mut out: Vec<u8> = Vec::new();
+    dici_to(&mut out, &AgoType::String("salve".to_string()));
+    dici_to(&mut out, &AgoType::Bool(true));
+    assert_eq!(String::from_utf8(out).unwrap(), "salve\ntrue\n");
 }
 
 #[test]
-fn test_bitwise_operators() {
-    assert_eq!(
-        bitwise_and(&AgoType::Int(6), &AgoType::Int(3)),
-        AgoType::Int(2)
-    ); // 110 & 011 = 010
+fn test_formatta() {
+    let template = AgoType::String("{} has {} legions".to_string());
+    let args = AgoType::ListAny(vec![
+        AgoType::String("Caesar".to_string()),
+        AgoType::Int(10),
+    ]);
     assert_eq!(
-        bitwise_or(&AgoType::Int(6), &AgoType::Int(3)),
-        AgoType::Int(7)
-    ); // 110 | 011 = 111
+        formatta(&template, &args),
+        AgoType::String("Caesar has 10 legions".to_string())
+    );
+
+    let escaped = AgoType::String("{{{}}} and {{}}".to_string());
     assert_eq!(
-        bitwise_xor(&AgoType::Int(6), &AgoType::Int(3)),
-        AgoType::Int(5)
-    ); // 110 ^ 011 = 101
+        formatta(&escaped, &AgoType::ListAny(vec![AgoType::Bool(true)])),
+        AgoType::String("{true} and {}".to_string())
+    );
 }
 
 #[test]
-#[should_panic]
-fn test_bitwise_panic() {
-    bitwise_and(&AgoType::Int(6), &AgoType::Float(3.0));
+#[should_panic(expected = "Format template has 2 placeholders, but 1 arguments were given")]
+fn test_formatta_count_mismatch() {
+    formatta(
+        &AgoType::String("{} {}".to_string()),
+        &AgoType::ListAny(vec![AgoType::Int(1)]),
+    );
 }
 
 #[test]
-fn test_unary_operators() {
-    assert_eq!(unary_minus(&AgoType::Int(5)), AgoType::Int(-5));
-    assert_eq!(unary_minus(&AgoType::Float(5.0)), AgoType::Float(-5.0));
-    assert_eq!(unary_plus(&AgoType::Int(5)), AgoType::Int(5));
+fn test_replica_is_independent() {
+    let mut original = AgoType::ListAny(vec![sample_struct(), AgoType::IntList(vec![1, 2])]);
+    let copy = replica(&original);
+
+    set(&mut original, &AgoType::Int(1), &AgoType::Null);
+    if let AgoType::ListAny(items) = &mut original {
+        set(
+            &mut items[0],
+            &AgoType::String("a".to_string()),
+            &AgoType::Int(99),
+        );
+    }
+
+    assert_eq!(
+        copy,
+        AgoType::ListAny(vec![sample_struct(), AgoType::IntList(vec![1, 2])])
+    );
 }
 
 #[test]
-fn test_contains() {
-    // In String
+fn test_longitudo() {
+    assert_eq!(longitudo(&AgoType::IntList(vec![1, 2, 3])), AgoType::Int(3));
+    assert_eq!(longitudo(&AgoType::FloatList(vec![])), AgoType::Int(0));
+    assert_eq!(longitudo(&AgoType::BoolList(vec![true])), AgoType::Int(1));
     assert_eq!(
-        contains(
-            &AgoType::String("hello".to_string()),
-            &AgoType::String("ell".to_string())
-        ),
-        AgoType::Bool(true)
+        longitudo(&AgoType::StringList(vec!["a".to_string(), "b".to_string()])),
+        AgoType::Int(2)
     );
+    assert_eq!(longitudo(&sample_any_list()), AgoType::Int(3));
+    // Characters, not bytes
     assert_eq!(
-        contains(
-            &AgoType::String("hello".to_string()),
-            &AgoType::String("z".to_string())
-        ),
-        AgoType::Bool(false)
+        longitudo(&AgoType::String("añō".to_string())),
+        AgoType::Int(3)
+    );
+    assert_eq!(longitudo(&sample_struct()), AgoType::Int(2));
+    assert_eq!(longitudo(&AgoType::Struct(HashMap::new())), AgoType::Int(0));
+    assert_eq!(
+        longitudo(&slice(&AgoType::Int(1), &AgoType::Int(5))),
+        AgoType::Int(5)
+    );
+    assert_eq!(
+        longitudo(&sliceto(&AgoType::Int(1), &AgoType::Int(5))),
+        AgoType::Int(4)
+    );
+    assert_eq!(
+        longitudo(&slice(&AgoType::Int(5), &AgoType::Int(1))),
+        AgoType::Int(0)
     );
+}
 
-    // In Struct (key)
+#[test]
+#[should_panic(expected = "Cannot take the length of")]
+fn test_longitudo_scalar() {
+    longitudo(&AgoType::Int(5));
+}
+
+#[test]
+fn test_vacuum() {
+    assert_eq!(vacuum(&AgoType::IntList(vec![])), AgoType::Bool(true));
+    assert_eq!(vacuum(&AgoType::IntList(vec![1])), AgoType::Bool(false));
     assert_eq!(
-        contains(&sample_struct(), &AgoType::String("a".to_string())),
+        vacuum(&AgoType::String("".to_string())),
         AgoType::Bool(true)
     );
     assert_eq!(
-        contains(&sample_struct(), &AgoType::String("z".to_string())),
+        vacuum(&AgoType::String("a".to_string())),
         AgoType::Bool(false)
     );
-
-    // In List
     assert_eq!(
-        contains(&AgoType::IntList(vec![1, 2, 3]), &AgoType::Int(2)),
+        vacuum(&AgoType::Struct(HashMap::new())),
         AgoType::Bool(true)
     );
+    assert_eq!(vacuum(&sample_struct()), AgoType::Bool(false));
     assert_eq!(
-        contains(&AgoType::IntList(vec![1, 2, 3]), &AgoType::Int(4)),
+        vacuum(&sliceto(&AgoType::Int(3), &AgoType::Int(3))),
+        AgoType::Bool(true)
+    );
+    assert_eq!(
+        vacuum(&slice(&AgoType::Int(3), &AgoType::Int(3))),
         AgoType::Bool(false)
     );
+    assert_eq!(vacuum(&AgoType::Null), AgoType::Bool(true));
+}
+
+#[test]
+fn test_wide_range_length() {
+    let wide = sliceto(&AgoType::Int(0), &AgoType::Int(i128::MAX));
+    assert_eq!(longitudo(&wide), AgoType::Int(i128::MAX));
+    let wide = slice(&AgoType::Int(0), &AgoType::Int(i128::MAX - 1));
+    assert_eq!(longitudo(&wide), AgoType::Int(i128::MAX));
     assert_eq!(
-        contains(&sample_any_list(), &AgoType::String("two".to_string())),
-        AgoType::Bool(true)
+        vacuum(&sliceto(&AgoType::Int(i128::MIN), &AgoType::Int(i128::MAX))),
+        AgoType::Bool(false)
     );
 }
 
 #[test]
-fn test_elvis() {
-    let val = AgoType::Int(10);
-    let default = AgoType::Int(20);
-    let null = AgoType::Null;
+#[should_panic(expected = "Range length does not fit in an Int")]
+fn test_wide_range_length_overflow() {
+    longitudo(&sliceto(&AgoType::Int(i128::MIN), &AgoType::Int(i128::MAX)));
+}
+
+#[test]
+#[should_panic(expected = "Cannot check emptiness of")]
+fn test_vacuum_scalar() {
+    vacuum(&AgoType::Float(0.0));
+}
+
+#[test]
+fn test_caput_cauda_ultimum() {
+    let list = AgoType::IntList(vec![1, 2, 3]);
+    assert_eq!(caput(&list), AgoType::Int(1));
+    assert_eq!(cauda(&list), AgoType::IntList(vec![2, 3]));
+    assert_eq!(ultimum(&list), AgoType::Int(3));
+
+    let single = AgoType::ListAny(vec![AgoType::Null]);
+    assert_eq!(caput(&single), AgoType::Null);
+    assert_eq!(cauda(&single), AgoType::ListAny(vec![]));
+    assert_eq!(ultimum(&single), AgoType::Null);
+
+    let s = AgoType::String("Roma".to_string());
+    assert_eq!(caput(&s), AgoType::String("R".to_string()));
+    assert_eq!(cauda(&s), AgoType::String("oma".to_string()));
+    assert_eq!(ultimum(&s), AgoType::String("a".to_string()));
+
+    let floats = AgoType::FloatList(vec![0.5, 1.5]);
+    assert_eq!(caput(&floats), AgoType::Float(0.5));
+    assert_eq!(ultimum(&floats), AgoType::Float(1.5));
+    let words = AgoType::StringList(vec!["ave".to_string(), "vale".to_string()]);
+    assert_eq!(caput(&words), AgoType::String("ave".to_string()));
+    assert_eq!(ultimum(&words), AgoType::String("vale".to_string()));
+    assert_eq!(
+        ultimum(&AgoType::BoolList(vec![true, false])),
+        AgoType::Bool(false)
+    );
+
+    assert_eq!(cauda(&AgoType::IntList(vec![])), AgoType::IntList(vec![]));
+    assert_eq!(
+        cauda(&AgoType::String("".to_string())),
+        AgoType::String("".to_string())
+    );
+}
+
+#[test]
+#[should_panic(expected = "empty list")]
+fn test_caput_empty() {
+    caput(&AgoType::StringList(vec![]));
+}
+
+#[test]
+#[should_panic(expected = "empty list")]
+fn test_ultimum_empty() {
+    ultimum(&AgoType::ListAny(vec![]));
+}
+
+#[test]
+fn test_bool_ad_float() {
+    assert_eq!(bool_ad_float(&AgoType::Bool(true)), AgoType::Float(1.0));
+    assert_eq!(bool_ad_float(&AgoType::Bool(false)), AgoType::Float(0.0));
+    // The cast keeps its quirky mapping
+    assert_eq!(
+        AgoType::Bool(true).as_type(TargetType::Float),
+        AgoType::Float(4.2)
+    );
+}
+
+#[test]
+fn test_rounded_lists() {
+    let floats = AgoType::FloatList(vec![1.5, -1.5, 2.2, -2.7]);
+    assert_eq!(floor_list(&floats), AgoType::IntList(vec![1, -2, 2, -3]));
+    assert_eq!(ceil_list(&floats), AgoType::IntList(vec![2, -1, 3, -2]));
+    assert_eq!(round_list(&floats), AgoType::IntList(vec![2, -2, 2, -3]));
+    // The plain cast truncates towards zero
+    assert_eq!(
+        floats.as_type(TargetType::IntList),
+        AgoType::IntList(vec![1, -1, 2, -2])
+    );
+}
+
+#[test]
+#[should_panic(expected = "out of range for an Int")]
+fn test_round_list_out_of_range() {
+    round_list(&AgoType::FloatList(vec![f64::INFINITY]));
+}
+
+#[test]
+fn test_repete() {
+    assert_eq!(
+        repete(&AgoType::Int(0), &AgoType::Int(3)),
+        AgoType::IntList(vec![0, 0, 0])
+    );
+    assert_eq!(
+        repete(&AgoType::String("ave".to_string()), &AgoType::Int(2)),
+        AgoType::StringList(vec!["ave".to_string(), "ave".to_string()])
+    );
+    assert_eq!(
+        repete(&sample_struct(), &AgoType::Int(2)),
+        AgoType::ListAny(vec![sample_struct(), sample_struct()])
+    );
+    assert_eq!(
+        repete(&AgoType::Bool(true), &AgoType::Int(0)),
+        AgoType::BoolList(vec![])
+    );
+}
+
+#[test]
+#[should_panic(expected = "Repeat count must be a non-negative Int")]
+fn test_repete_negative_count() {
+    repete(&AgoType::Int(0), &AgoType::Int(-1));
+}
+
+#[test]
+fn test_singulum() {
+    assert_eq!(
+        singulum(&AgoType::String("ab".to_string())),
+        AgoType::StringList(vec!["ab".to_string()])
+    );
+    assert_eq!(singulum(&AgoType::Int(5)), AgoType::IntList(vec![5]));
+    assert_eq!(
+        singulum(&sample_struct()),
+        AgoType::ListAny(vec![sample_struct()])
+    );
+}
+
+#[test]
+#[should_panic(expected = "Repeat count 18446744073709551616 is too large")]
+fn test_repete_huge_count() {
+    repete(&AgoType::Int(0), &AgoType::Int(1 << 64));
+}
+
+#[test]
+fn test_sequentia() {
+    assert_eq!(
+        sequentia(&AgoType::Int(1), &AgoType::Int(4), &AgoType::Int(3)),
+        AgoType::IntList(vec![1, 4, 7, 10])
+    );
+    assert_eq!(
+        sequentia(&AgoType::Int(10), &AgoType::Int(3), &AgoType::Int(-5)),
+        AgoType::IntList(vec![10, 5, 0])
+    );
+    assert_eq!(
+        sequentia(&AgoType::Int(0), &AgoType::Int(3), &AgoType::Float(0.5)),
+        AgoType::FloatList(vec![0.0, 0.5, 1.0])
+    );
+    assert_eq!(
+        sequentia(&AgoType::Int(1), &AgoType::Int(0), &AgoType::Int(1)),
+        AgoType::IntList(vec![])
+    );
+}
+
+#[test]
+fn test_permuta() {
+    let mut ints = AgoType::IntList(vec![1, 2, 3]);
+    permuta(&mut ints, &AgoType::Int(0), &AgoType::Int(2));
+    assert_eq!(ints, AgoType::IntList(vec![3, 2, 1]));
+
+    let mut any = sample_any_list();
+    permuta(&mut any, &AgoType::Int(1), &AgoType::Int(2));
+    assert_eq!(
+        any,
+        AgoType::ListAny(vec![
+            AgoType::Int(1),
+            AgoType::Bool(false),
+            AgoType::String("two".to_string()),
+        ])
+    );
+}
+
+#[test]
+#[should_panic(expected = "Index out of bounds: 3")]
+fn test_permuta_out_of_bounds() {
+    let mut ints = AgoType::IntList(vec![1, 2, 3]);
+    permuta(&mut ints, &AgoType::Int(0), &AgoType::Int(3));
+}
+
+#[test]
+#[should_panic(expected = "Index out of bounds: 18446744073709551616")]
+fn test_permuta_huge_index() {
+    let mut ints = AgoType::IntList(vec![10, 20, 30]);
+    permuta(&mut ints, &AgoType::Int(1 << 64), &AgoType::Int(2));
+}
+
+#[test]
+fn test_misce() {
+    let list = AgoType::IntList((1..=20).collect());
+    let first = misce(&list, &AgoType::Int(42));
+    assert_eq!(first, misce(&list, &AgoType::Int(42)));
+    assert_ne!(first, list);
+
+    // Same multiset of elements
+    let mut sorted = first.clone();
+    ordina_per(&mut sorted, &identity());
+    assert_eq!(sorted, list);
+}
+
+#[test]
+#[should_panic(expected = "Shuffle seed must be an Int")]
+fn test_misce_bad_seed() {
+    misce(&AgoType::IntList(vec![1]), &AgoType::Float(1.0));
+}
+
+#[test]
+fn test_fortuna_deterministic() {
+    let mut seed_a = AgoType::Int(7);
+    let mut seed_b = AgoType::Int(7);
+    let run_a: Vec<AgoType> = (0..5).map(|_| fortuna(&mut seed_a)).collect();
+    let run_b: Vec<AgoType> = (0..5).map(|_| fortuna(&mut seed_b)).collect();
+    assert_eq!(run_a, run_b);
+    assert_ne!(run_a[0], run_a[1]);
+    assert_ne!(seed_a, AgoType::Int(7));
+}
+
+#[test]
+fn test_fortuna_intra_bounds() {
+    let mut seed = AgoType::Int(123);
+    for _ in 0..1000 {
+        match fortuna_intra(&mut seed, &AgoType::Int(-3), &AgoType::Int(3)) {
+            AgoType::Int(n) => assert!((-3..=3).contains(&n)),
+            other => panic!("Expected an Int, got {:?}", other),
+        }
+    }
+}
+
+#[test]
+fn test_tempus() {
+    // 2020-01-01T00:00:00Z in milliseconds
+    match tempus() {
+        AgoType::Int(ms) => assert!(ms > 1_577_836_800_000),
+        other => panic!("Expected an Int, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_ambiens() {
+    std::env::set_var("AGO_STDLIB_TEST_AMBIENS", "salve");
+    assert_eq!(
+        ambiens(&AgoType::String("AGO_STDLIB_TEST_AMBIENS".to_string())),
+        AgoType::String("salve".to_string())
+    );
+    assert_eq!(
+        ambiens(&AgoType::String("AGO_STDLIB_TEST_UNSET_VAR".to_string())),
+        AgoType::Null
+    );
+}
+
+#[test]
+fn test_args_from() {
+    let raw = vec!["./prog".to_string(), "-v".to_string(), "in.txt".to_string()];
+    assert_eq!(
+        args_from(raw.into_iter()),
+        AgoType::StringList(vec!["-v".to_string(), "in.txt".to_string()])
+    );
+    assert_eq!(
+        args_from(vec!["./prog".to_string()].into_iter()),
+        AgoType::StringList(vec![])
+    );
+    assert_eq!(args_from(std::iter::empty()), AgoType::StringList(vec![]));
+}
+
+#[test]
+fn test_incipit_desinit() {
+    let s = AgoType::String("carpe diem".to_string());
+    assert_eq!(
+        incipit(&s, &AgoType::String("carpe".to_string())),
+        AgoType::Bool(true)
+    );
+    assert_eq!(
+        incipit(&s, &AgoType::String("diem".to_string())),
+        AgoType::Bool(false)
+    );
+    assert_eq!(
+        desinit(&s, &AgoType::String("diem".to_string())),
+        AgoType::Bool(true)
+    );
+    assert_eq!(
+        desinit(&s, &AgoType::String("carpe".to_string())),
+        AgoType::Bool(false)
+    );
+
+    let empty = AgoType::String("".to_string());
+    assert_eq!(incipit(&s, &empty), AgoType::Bool(true));
+    assert_eq!(desinit(&s, &empty), AgoType::Bool(true));
+}
+
+#[test]
+#[should_panic(expected = "incipit expects two Strings")]
+fn test_incipit_non_string() {
+    incipit(&AgoType::String("a".to_string()), &AgoType::Int(1));
+}
+
+#[test]
+fn test_imple_sinistra_dextra() {
+    let dot = AgoType::String("·".to_string());
+    assert_eq!(
+        imple_sinistra(&AgoType::String("42".to_string()), &AgoType::Int(5), &dot),
+        AgoType::String("···42".to_string())
+    );
+    assert_eq!(
+        imple_dextra(&AgoType::String("42".to_string()), &AgoType::Int(5), &dot),
+        AgoType::String("42···".to_string())
+    );
+    // Width counts characters, not bytes
+    assert_eq!(
+        imple_sinistra(&AgoType::String("ñ".to_string()), &AgoType::Int(2), &dot),
+        AgoType::String("·ñ".to_string())
+    );
+    let long = AgoType::String("longissimus".to_string());
+    assert_eq!(imple_sinistra(&long, &AgoType::Int(3), &dot), long);
+    assert_eq!(imple_dextra(&long, &AgoType::Int(3), &dot), long);
+}
+
+#[test]
+#[should_panic(expected = "fill must be a single character")]
+fn test_imple_multi_char_fill() {
+    imple_dextra(
+        &AgoType::String("a".to_string()),
+        &AgoType::Int(5),
+        &AgoType::String("ab".to_string()),
+    );
+}
+
+#[test]
+#[should_panic(expected = "imple_sinistra width 18446744073709551621 is too large")]
+fn test_imple_huge_width() {
+    imple_sinistra(
+        &AgoType::String("ab".to_string()),
+        &AgoType::Int((1 << 64) + 5),
+        &AgoType::String("*".to_string()),
+    );
+}
+
+#[test]
+fn test_finde_n() {
+    let line = AgoType::String("key=value=withequals".to_string());
+    let eq = AgoType::String("=".to_string());
+    assert_eq!(
+        finde_n(&line, &eq, &AgoType::Int(2)),
+        AgoType::StringList(vec!["key".to_string(), "value=withequals".to_string()])
+    );
+    assert_eq!(
+        finde_n(&line, &eq, &AgoType::Int(10)),
+        AgoType::StringList(vec![
+            "key".to_string(),
+            "value".to_string(),
+            "withequals".to_string()
+        ])
+    );
+
+    // A max beyond usize means no limit rather than a truncated one.
+    let all = AgoType::StringList(vec![
+        "key".to_string(),
+        "value".to_string(),
+        "withequals".to_string(),
+    ]);
+    for max in [1 << 64, (1 << 64) + 1] {
+        assert_eq!(finde_n(&line, &eq, &AgoType::Int(max)), all);
+    }
+}
+
+#[test]
+#[should_panic(expected = "max must be a positive Int")]
+fn test_finde_n_zero_max() {
+    finde_n(
+        &AgoType::String("a=b".to_string()),
+        &AgoType::String("=".to_string()),
+        &AgoType::Int(0),
+    );
+}
+
+#[test]
+fn test_lineae() {
+    assert_eq!(
+        lineae(&AgoType::String("veni\r\nvidi\nvici\r\n".to_string())),
+        AgoType::StringList(vec![
+            "veni".to_string(),
+            "vidi".to_string(),
+            "vici".to_string()
+        ])
+    );
+    assert_eq!(
+        lineae(&AgoType::String("a\n\nb".to_string())),
+        AgoType::StringList(vec!["a".to_string(), "".to_string(), "b".to_string()])
+    );
+}
+
+#[test]
+fn test_verba() {
+    assert_eq!(
+        verba(&AgoType::String("  alea   iacta\t est \n".to_string())),
+        AgoType::StringList(vec![
+            "alea".to_string(),
+            "iacta".to_string(),
+            "est".to_string()
+        ])
+    );
+    assert_eq!(
+        verba(&AgoType::String("   ".to_string())),
+        AgoType::StringList(vec![])
+    );
+}
+
+#[test]
+fn test_est_numerus() {
+    let check = |s: &str| est_numerus(&AgoType::String(s.to_string()));
+    assert_eq!(check("42"), AgoType::Bool(true));
+    assert_eq!(check("-3.5"), AgoType::Bool(true));
+    assert_eq!(check("+8"), AgoType::Bool(true));
+    assert_eq!(check("  7 "), AgoType::Bool(true));
+    assert_eq!(check("abc"), AgoType::Bool(false));
+    assert_eq!(check(""), AgoType::Bool(false));
+}
+
+#[test]
+fn test_binarium_hexadecimale() {
+    assert_eq!(
+        ad_binarium(&AgoType::Int(5)),
+        AgoType::String("101".to_string())
+    );
+    assert_eq!(
+        ad_hexadecimale(&AgoType::Int(-255)),
+        AgoType::String("-ff".to_string())
+    );
+    for n in [0, 1, 5, -5, 255, -4096, i128::MAX, i128::MIN] {
+        let n = AgoType::Int(n);
+        assert_eq!(ex_binarium(&ad_binarium(&n)), n);
+        assert_eq!(ex_hexadecimale(&ad_hexadecimale(&n)), n);
+    }
+}
+
+#[test]
+#[should_panic(expected = "cannot parse '102' in base 2")]
+fn test_ex_binarium_malformed() {
+    ex_binarium(&AgoType::String("102".to_string()));
+}
+
+#[test]
+#[should_panic(expected = "ad_hexadecimale expects an Int")]
+fn test_ad_hexadecimale_non_int() {
+    ad_hexadecimale(&AgoType::Float(1.0));
+}
+
+#[test]
+fn test_maximus_divisor_minimum_multiplum() {
+    let gcd = |a, b| maximus_divisor(&AgoType::Int(a), &AgoType::Int(b));
+    let lcm = |a, b| minimum_multiplum(&AgoType::Int(a), &AgoType::Int(b));
+
+    // Coprime
+    assert_eq!(gcd(8, 15), AgoType::Int(1));
+    assert_eq!(lcm(8, 15), AgoType::Int(120));
+
+    // Common factor, signs ignored
+    assert_eq!(gcd(12, -18), AgoType::Int(6));
+    assert_eq!(lcm(-12, 18), AgoType::Int(36));
+
+    // Zero operands
+    assert_eq!(gcd(0, 7), AgoType::Int(7));
+    assert_eq!(gcd(0, 0), AgoType::Int(0));
+    assert_eq!(lcm(0, 7), AgoType::Int(0));
+}
+
+#[test]
+#[should_panic(expected = "Integer overflow in minimum_multiplum")]
+fn test_minimum_multiplum_overflow() {
+    minimum_multiplum(&AgoType::Int(i128::MAX), &AgoType::Int(i128::MAX - 1));
+}
+
+#[test]
+fn test_trigonometry_and_logarithms() {
+    use std::f64::consts::{E, PI};
+    assert_float_near(sinus(&AgoType::Int(0)), 0.0);
+    assert_float_near(sinus(&AgoType::Float(PI / 2.0)), 1.0);
+    assert_float_near(cosinus(&AgoType::Int(0)), 1.0);
+    assert_float_near(cosinus(&AgoType::Float(PI)), -1.0);
+    assert_float_near(tangens(&AgoType::Float(PI / 4.0)), 1.0);
+    assert_float_near(logarithmus(&AgoType::Float(E)), 1.0);
+    assert_float_near(logarithmus(&AgoType::Int(1)), 0.0);
+    assert_float_near(
+        logarithmus_base(&AgoType::Int(1000), &AgoType::Int(10)),
+        3.0,
+    );
+    assert_float_near(exponentialis(&AgoType::Int(0)), 1.0);
+    assert_float_near(exponentialis(&AgoType::Int(1)), E);
+}
+
+#[test]
+#[should_panic(expected = "logarithmus is undefined for non-positive value")]
+fn test_logarithmus_non_positive() {
+    logarithmus(&AgoType::Int(0));
+}
+
+#[test]
+#[should_panic(expected = "logarithmus_base is undefined for base 1")]
+fn test_logarithmus_base_one() {
+    logarithmus_base(&AgoType::Int(8), &AgoType::Int(1));
+}
+
+#[test]
+fn test_potentia_modularis() {
+    let pow_mod =
+        |b, e, m| potentia_modularis(&AgoType::Int(b), &AgoType::Int(e), &AgoType::Int(m));
+    assert_eq!(pow_mod(2, 10, 1000), AgoType::Int(24));
+    assert_eq!(pow_mod(3, 0, 7), AgoType::Int(1));
+    assert_eq!(pow_mod(5, 3, 1), AgoType::Int(0));
+    assert_eq!(pow_mod(-2, 3, 5), AgoType::Int(2));
+    // Fermat: a^(p-1) = 1 mod p, far beyond what naive exponentiation could hold
+    let p = 170_141_183_460_469_231_731_687_303_715_884_105_727; // 2^127 - 1
+    assert_eq!(pow_mod(3, p - 1, p), AgoType::Int(1));
+    assert_eq!(pow_mod(2, 1_000_000_007, 1_000_000_007), AgoType::Int(2));
+}
+
+#[test]
+#[should_panic(expected = "modulus must be positive")]
+fn test_potentia_modularis_zero_modulus() {
+    potentia_modularis(&AgoType::Int(2), &AgoType::Int(3), &AgoType::Int(0));
+}
+
+#[test]
+#[should_panic(expected = "exponent must be non-negative")]
+fn test_potentia_modularis_negative_exponent() {
+    potentia_modularis(&AgoType::Int(2), &AgoType::Int(-1), &AgoType::Int(5));
+}
+
+#[test]
+fn test_signum_par() {
+    assert_eq!(signum(&AgoType::Int(-7)), AgoType::Int(-1));
+    assert_eq!(signum(&AgoType::Int(0)), AgoType::Int(0));
+    assert_eq!(signum(&AgoType::Int(3)), AgoType::Int(1));
+    assert_eq!(signum(&AgoType::Float(-0.5)), AgoType::Int(-1));
+    assert_eq!(signum(&AgoType::Float(-0.0)), AgoType::Int(0));
+    assert_eq!(signum(&AgoType::Float(2.5)), AgoType::Int(1));
+
+    assert_eq!(par(&AgoType::Int(4)), AgoType::Bool(true));
+    assert_eq!(par(&AgoType::Int(0)), AgoType::Bool(true));
+    assert_eq!(par(&AgoType::Int(-3)), AgoType::Bool(false));
+}
+
+#[test]
+#[should_panic(expected = "par expects an Int")]
+fn test_par_float() {
+    par(&AgoType::Float(2.0));
+}
+
+#[test]
+#[allow(clippy::approx_constant)]
+fn test_rotundum_ad() {
+    let two = AgoType::Int(2);
+    assert_float_near(rotundum_ad(&AgoType::Float(3.14159), &two), 3.14);
+    assert_float_near(rotundum_ad(&AgoType::Float(0.125), &two), 0.13);
+    assert_float_near(rotundum_ad(&AgoType::Float(-0.125), &two), -0.13);
+    assert_eq!(
+        rotundum_ad(&AgoType::Float(2.5), &AgoType::Int(0)),
+        AgoType::Float(3.0)
+    );
+}
+
+#[test]
+fn test_rotundum_ad_huge_value() {
+    assert_eq!(
+        rotundum_ad(&AgoType::Float(1e300), &AgoType::Int(10)),
+        AgoType::Float(1e300)
+    );
+    assert_eq!(
+        rotundum_ad(&AgoType::Float(-1e300), &AgoType::Int(400)),
+        AgoType::Float(-1e300)
+    );
+}
+
+#[test]
+#[allow(clippy::approx_constant)]
+#[should_panic(expected = "Decimal places must be a non-negative Int")]
+fn test_rotundum_ad_negative_places() {
+    rotundum_ad(&AgoType::Float(3.14159), &AgoType::Int(-1));
+}
+
+#[test]
+fn test_chr_round_trip() {
+    assert_eq!(ad_chr(&AgoType::Int(65)), AgoType::String("A".to_string()));
+    assert_eq!(ex_chr(&AgoType::String("A".to_string())), AgoType::Int(65));
+
+    let crab = AgoType::String("\u{1F980}".to_string());
+    assert_eq!(ex_chr(&crab), AgoType::Int(0x1F980));
+    assert_eq!(ad_chr(&ex_chr(&crab)), crab);
+}
+
+#[test]
+#[should_panic(expected = "not a valid Unicode scalar value")]
+fn test_ad_chr_surrogate() {
+    ad_chr(&AgoType::Int(0xD800));
+}
+
+#[test]
+#[should_panic(expected = "ex_chr expects a single character")]
+fn test_ex_chr_multiple_chars() {
+    ex_chr(&AgoType::String("ab".to_string()));
+}
+
+#[test]
+fn test_cum() {
+    let base = sample_struct();
+    let mut updates = HashMap::new();
+    updates.insert("b".to_string(), AgoType::String("world".to_string()));
+    updates.insert("c".to_string(), AgoType::Bool(true));
+
+    let updated = cum(&base, &AgoType::Struct(updates));
+    let mut expected = HashMap::new();
+    expected.insert("a".to_string(), AgoType::Int(1));
+    expected.insert("b".to_string(), AgoType::String("world".to_string()));
+    expected.insert("c".to_string(), AgoType::Bool(true));
+    assert_eq!(updated, AgoType::Struct(expected));
+    assert_eq!(base, sample_struct());
+}
+
+#[test]
+#[should_panic(expected = "Cannot call 'cum'")]
+fn test_cum_non_struct() {
+    cum(&sample_struct(), &AgoType::Int(1));
+}
+
+#[test]
+fn test_omitte_claves() {
+    let base = sample_struct();
+    let keys = AgoType::StringList(vec!["b".to_string(), "missing".to_string()]);
+
+    let mut expected = HashMap::new();
+    expected.insert("a".to_string(), AgoType::Int(1));
+    assert_eq!(omitte_claves(&base, &keys), AgoType::Struct(expected));
+    assert_eq!(
+        omitte_claves(&base, &AgoType::StringList(vec![])),
+        sample_struct()
+    );
+    assert_eq!(base, sample_struct());
+}
+
+#[test]
+fn test_fusio_profunda() {
+    let nested = |pairs: &[(&str, AgoType)]| {
+        AgoType::Struct(
+            pairs
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.clone()))
+                .collect(),
+        )
+    };
+    let left = nested(&[
+        (
+            "config",
+            nested(&[
+                ("depth", AgoType::Int(1)),
+                ("name", AgoType::String("a".to_string())),
+            ]),
+        ),
+        ("meta", nested(&[("x", AgoType::Int(1))])),
+        ("only_left", AgoType::Bool(true)),
+    ]);
+    let right = nested(&[
+        (
+            "config",
+            nested(&[("depth", AgoType::Int(2)), ("extra", AgoType::Null)]),
+        ),
+        ("meta", AgoType::Int(0)),
+        ("only_right", AgoType::Bool(false)),
+    ]);
+
+    let expected = nested(&[
+        (
+            "config",
+            nested(&[
+                ("depth", AgoType::Int(2)),
+                ("name", AgoType::String("a".to_string())),
+                ("extra", AgoType::Null),
+            ]),
+        ),
+        ("meta", AgoType::Int(0)),
+        ("only_left", AgoType::Bool(true)),
+        ("only_right", AgoType::Bool(false)),
+    ]);
+    assert_eq!(fusio_profunda(&left, &right), expected);
+}
+
+#[test]
+#[should_panic(expected = "Cannot call 'fusio_profunda'")]
+fn test_fusio_profunda_non_struct() {
+    fusio_profunda(&sample_struct(), &AgoType::IntList(vec![1]));
+}
+
+#[test]
+fn test_profunda_get() {
+    let doc = nested_document();
+    let users = AgoType::String("users".to_string());
+    assert_eq!(
+        profunda_get(
+            &doc,
+            &path(vec![
+                users.clone(),
+                AgoType::Int(0),
+                AgoType::String("name".to_string())
+            ])
+        ),
+        AgoType::String("Ada".to_string())
+    );
+    assert_eq!(
+        profunda_get(
+            &doc,
+            &path(vec![
+                users,
+                AgoType::Int(0),
+                AgoType::String("tags".to_string()),
+                AgoType::Int(1)
+            ])
+        ),
+        AgoType::String("y".to_string())
+    );
+    assert_eq!(profunda_get(&doc, &AgoType::StringList(vec![])), doc);
+}
+
+#[test]
+#[should_panic(expected = "Path not found: users.3")]
+fn test_profunda_get_missing() {
+    profunda_get(
+        &nested_document(),
+        &path(vec![
+            AgoType::String("users".to_string()),
+            AgoType::Int(3),
+            AgoType::String("name".to_string()),
+        ]),
+    );
+}
+
+#[test]
+fn test_profunda_set() {
+    let mut doc = nested_document();
+    let name_path = path(vec![
+        AgoType::String("users".to_string()),
+        AgoType::Int(0),
+        AgoType::String("name".to_string()),
+    ]);
+    profunda_set(&mut doc, &name_path, &AgoType::String("Grace".to_string()));
+    assert_eq!(
+        profunda_get(&doc, &name_path),
+        AgoType::String("Grace".to_string())
+    );
+
+    let tag_path = path(vec![
+        AgoType::String("users".to_string()),
+        AgoType::Int(0),
+        AgoType::String("tags".to_string()),
+        AgoType::Int(0),
+    ]);
+    profunda_set(&mut doc, &tag_path, &AgoType::String("z".to_string()));
+    assert_eq!(
+        profunda_get(&doc, &tag_path),
+        AgoType::String("z".to_string())
+    );
+}
+
+#[test]
+#[should_panic(expected = "Path not found: profile")]
+fn test_profunda_set_missing() {
+    let mut doc = nested_document();
+    profunda_set(
+        &mut doc,
+        &AgoType::StringList(vec!["profile".to_string(), "age".to_string()]),
+        &AgoType::Int(3),
+    );
+}
+
+#[test]
+fn test_ad_textum() {
+    let comma = AgoType::String(", ".to_string());
+    assert_eq!(
+        ad_textum(&AgoType::IntList(vec![1, 2, 3]), &comma),
+        AgoType::String("1, 2, 3".to_string())
+    );
+    assert_eq!(
+        ad_textum(&sample_any_list(), &AgoType::String("; ".to_string())),
+        AgoType::String("1; two; false".to_string())
+    );
+    assert_eq!(
+        ad_textum(&sample_struct(), &comma),
+        AgoType::String("a: 1, b: hello".to_string())
+    );
+    assert_eq!(
+        ad_textum(&AgoType::StringList(vec![]), &comma),
+        AgoType::String(String::new())
+    );
+}
+
+#[test]
+#[should_panic(expected = "Cannot call 'ad_textum'")]
+fn test_ad_textum_scalar() {
+    ad_textum(&AgoType::Int(1), &AgoType::String(",".to_string()));
+}
+
+#[test]
+fn test_est() {
+    let name = |n: &str| AgoType::String(n.to_string());
+    let range = AgoType::Range(AgoRange {
+        start: 0,
+        end: 2,
+        inclusive: true,
+    });
+    assert_eq!(est(&AgoType::Int(1), &name("Int")), AgoType::Bool(true));
+    assert_eq!(est(&AgoType::Int(1), &name("Float")), AgoType::Bool(false));
+    assert_eq!(est(&range, &name("Range")), AgoType::Bool(true));
+    assert_eq!(est(&range, &name("IntList")), AgoType::Bool(false));
+    assert_eq!(est(&AgoType::Null, &name("Null")), AgoType::Bool(true));
+    assert_eq!(est(&sample_struct(), &name("Null")), AgoType::Bool(false));
+}
+
+#[test]
+#[should_panic(expected = "est expects a type-name String")]
+fn test_est_non_string_type_name() {
+    est(&AgoType::Int(1), &AgoType::Int(1));
+}
+
+#[test]
+fn test_numera_vera() {
+    assert_eq!(
+        numera_vera(&AgoType::BoolList(vec![true, true, true])),
+        AgoType::Int(3)
+    );
+    assert_eq!(
+        numera_vera(&AgoType::BoolList(vec![false, false])),
+        AgoType::Int(0)
+    );
+    assert_eq!(
+        numera_vera(&AgoType::BoolList(vec![true, false, true, false])),
+        AgoType::Int(2)
+    );
+}
+
+#[test]
+#[should_panic(expected = "Cannot call 'numera_vera'")]
+fn test_numera_vera_non_boollist() {
+    numera_vera(&AgoType::IntList(vec![1, 0]));
+}
+
+#[test]
+fn test_abs_differentia() {
+    assert_eq!(
+        abs_differentia(&AgoType::Int(3), &AgoType::Int(10)),
+        AgoType::Int(7)
+    );
+    assert_eq!(
+        abs_differentia(&AgoType::Int(-4), &AgoType::Int(-9)),
+        AgoType::Int(5)
+    );
+    assert_float_near(abs_differentia(&AgoType::Int(1), &AgoType::Float(2.5)), 1.5);
+    assert_float_near(
+        abs_differentia(&AgoType::Float(-1.0), &AgoType::Int(1)),
+        2.0,
+    );
+    assert_eq!(
+        abs_differentia(&AgoType::Int(i128::MAX), &AgoType::Int(i128::MAX - 5)),
+        AgoType::Int(5)
+    );
+}
+
+#[test]
+#[should_panic(expected = "Integer overflow in abs_differentia")]
+fn test_abs_differentia_overflow() {
+    abs_differentia(&AgoType::Int(i128::MAX), &AgoType::Int(-1));
+}
+
+#[test]
+#[should_panic(expected = "abs_differentia expects an Int or Float")]
+fn test_abs_differentia_non_numeric() {
+    abs_differentia(&AgoType::Int(1), &AgoType::String("2".to_string()));
+}
+
+#[test]
+fn test_maxime_minime() {
+    let words = AgoType::StringList(vec![
+        "pear".to_string(),
+        "apple".to_string(),
+        "quince".to_string(),
+    ]);
+    assert_eq!(maxime(&words), AgoType::String("quince".to_string()));
+    assert_eq!(minime(&words), AgoType::String("apple".to_string()));
+
+    let numbers = AgoType::ListAny(vec![AgoType::Int(3), AgoType::Float(7.5), AgoType::Int(-2)]);
+    assert_eq!(maxime(&numbers), AgoType::Float(7.5));
+    assert_eq!(minime(&numbers), AgoType::Int(-2));
+}
+
+#[test]
+#[should_panic(expected = "Cannot reduce an empty list")]
+fn test_maxime_empty() {
+    maxime(&AgoType::IntList(vec![]));
+}
+
+#[test]
+#[should_panic(expected = "Cannot compare")]
+fn test_minime_incomparable() {
+    minime(&sample_any_list());
+}
+
+#[test]
+fn test_tabula() {
+    let keys = AgoType::StringList(vec!["a".to_string(), "b".to_string()]);
+    let values = AgoType::ListAny(vec![AgoType::Int(1), AgoType::String("hello".to_string())]);
+    assert_eq!(tabula(&keys, &values), sample_struct());
+
+    let duplicate_keys = AgoType::StringList(vec!["x".to_string(), "x".to_string()]);
+    let mut expected = HashMap::new();
+    expected.insert("x".to_string(), AgoType::Int(2));
+    assert_eq!(
+        tabula(&duplicate_keys, &AgoType::IntList(vec![1, 2])),
+        AgoType::Struct(expected)
+    );
+}
+
+#[test]
+#[should_panic(expected = "tabula needs as many values as keys")]
+fn test_tabula_length_mismatch() {
+    tabula(
+        &AgoType::StringList(vec!["a".to_string()]),
+        &AgoType::IntList(vec![1, 2]),
+    );
+}
+
+#[test]
+fn test_ex_tabula() {
+    let keys = AgoType::StringList(vec!["z".to_string(), "m".to_string(), "a".to_string()]);
+    let values = AgoType::IntList(vec![26, 13, 1]);
+    assert_eq!(
+        ex_tabula(&tabula(&keys, &values)),
+        AgoType::ListAny(vec![
+            AgoType::StringList(vec!["a".to_string(), "m".to_string(), "z".to_string()]),
+            AgoType::ListAny(vec![AgoType::Int(1), AgoType::Int(13), AgoType::Int(26)]),
+        ])
+    );
+    assert_eq!(
+        ex_tabula(&AgoType::Struct(HashMap::new())),
+        AgoType::ListAny(vec![AgoType::StringList(vec![]), AgoType::ListAny(vec![])])
+    );
+}
+
+#[test]
+fn test_ago_collector() {
+    let mut ints = AgoCollector::new();
+    for i in 1..=3 {
+        ints.push(AgoType::Int(i));
+    }
+    assert_eq!(ints.finish(), AgoType::IntList(vec![1, 2, 3]));
+
+    let mut mixed = AgoCollector::new();
+    mixed.push(AgoType::Int(1));
+    mixed.push(AgoType::String("two".to_string()));
+    mixed.push(AgoType::Bool(false));
+    assert_eq!(mixed.finish(), sample_any_list());
+
+    let mut structs = AgoCollector::new();
+    structs.push(sample_struct());
+    assert_eq!(structs.finish(), AgoType::ListAny(vec![sample_struct()]));
+
+    assert_eq!(AgoCollector::new().finish(), AgoType::ListAny(vec![]));
+}
+
+#[test]
+fn test_in_ordine() {
+    assert_eq!(
+        in_ordine(&AgoType::IntList(vec![1, 2, 5])),
+        AgoType::Bool(true)
+    );
+    assert_eq!(
+        in_ordine(&AgoType::FloatList(vec![1.0, 0.5])),
+        AgoType::Bool(false)
+    );
+    assert_eq!(
+        in_ordine(&AgoType::StringList(vec![
+            "a".to_string(),
+            "a".to_string(),
+            "b".to_string()
+        ])),
+        AgoType::Bool(true)
+    );
+    assert_eq!(
+        in_ordine(&AgoType::ListAny(vec![
+            AgoType::Int(1),
+            AgoType::Float(1.5),
+            AgoType::Int(2)
+        ])),
+        AgoType::Bool(true)
+    );
+    assert_eq!(in_ordine(&AgoType::IntList(vec![])), AgoType::Bool(true));
+    assert_eq!(in_ordine(&AgoType::IntList(vec![9])), AgoType::Bool(true));
+}
+
+#[test]
+#[should_panic(expected = "Cannot compare")]
+fn test_in_ordine_incomparable() {
+    in_ordine(&sample_any_list());
+}
+
+#[test]
+fn test_quaere_binarium() {
+    let list = AgoType::IntList(vec![2, 4, 6, 8, 10]);
+    assert_eq!(quaere_binarium(&list, &AgoType::Int(6)), AgoType::Int(2));
+    assert_eq!(quaere_binarium(&list, &AgoType::Int(2)), AgoType::Int(0));
+    assert_eq!(quaere_binarium(&list, &AgoType::Int(10)), AgoType::Int(4));
+    assert_eq!(quaere_binarium(&list, &AgoType::Int(7)), AgoType::Null);
+    assert_eq!(quaere_binarium(&list, &AgoType::Int(11)), AgoType::Null);
+    assert_eq!(
+        quaere_binarium(&AgoType::IntList(vec![]), &AgoType::Int(1)),
+        AgoType::Null
+    );
+
+    let words = AgoType::StringList(vec!["ante".to_string(), "post".to_string()]);
+    assert_eq!(
+        quaere_binarium(&words, &AgoType::String("post".to_string())),
+        AgoType::Int(1)
+    );
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "quaere_binarium requires a sorted list")]
+fn test_quaere_binarium_unsorted() {
+    quaere_binarium(&AgoType::IntList(vec![3, 1, 2]), &AgoType::Int(1));
+}
+
+#[test]
+fn test_aequalam_strictum() {
+    let nan = AgoType::Float(f64::NAN);
+    let zero = AgoType::Float(0.0);
+    let neg_zero = AgoType::Float(-0.0);
+
+    assert_eq!(aequalam(&nan, &nan), AgoType::Bool(false));
+    assert_eq!(aequalam_strictum(&nan, &nan), AgoType::Bool(true));
+
+    assert_eq!(aequalam(&zero, &neg_zero), AgoType::Bool(true));
+    assert_eq!(aequalam_strictum(&zero, &neg_zero), AgoType::Bool(false));
+
+    let nested = AgoType::ListAny(vec![nan.clone(), AgoType::FloatList(vec![f64::NAN])]);
+    assert_eq!(aequalam(&nested, &nested), AgoType::Bool(false));
+    assert_eq!(aequalam_strictum(&nested, &nested), AgoType::Bool(true));
+    assert_eq!(
+        aequalam_strictum(&sample_struct(), &sample_struct()),
+        AgoType::Bool(true)
+    );
+    assert_eq!(
+        aequalam_strictum(&AgoType::Int(1), &AgoType::Float(1.0)),
+        AgoType::Bool(false)
+    );
+}
+
+#[test]
+fn test_sub_chorda() {
+    let s = AgoType::String("salve mundi".to_string());
+    let sub =
+        |start: i128, length: i128| sub_chorda(&s, &AgoType::Int(start), &AgoType::Int(length));
+    assert_eq!(sub(0, 5), AgoType::String("salve".to_string()));
+    assert_eq!(sub(6, 100), AgoType::String("mundi".to_string()));
+    assert_eq!(sub(-5, 3), AgoType::String("mun".to_string()));
+    assert_eq!(sub(-50, 2), AgoType::String("sa".to_string()));
+    assert_eq!(sub(20, 2), AgoType::String(String::new()));
+    assert_eq!(
+        sub_chorda(
+            &AgoType::String("αβγ".to_string()),
+            &AgoType::Int(1),
+            &AgoType::Int(1)
+        ),
+        AgoType::String("β".to_string())
+    );
+}
+
+#[test]
+#[should_panic(expected = "sub_chorda length must be non-negative")]
+fn test_sub_chorda_negative_length() {
+    sub_chorda(
+        &AgoType::String("abc".to_string()),
+        &AgoType::Int(0),
+        &AgoType::Int(-1),
+    );
+}
+
+#[test]
+fn test_indenta() {
+    let prefix = AgoType::String("  ".to_string());
+    assert_eq!(
+        indenta(&AgoType::String("a\n\nb\r\nc\n".to_string()), &prefix),
+        AgoType::String("  a\n  \n  b\r\n  c\n".to_string())
+    );
+    assert_eq!(
+        indenta(&AgoType::String("solus".to_string()), &prefix),
+        AgoType::String("  solus".to_string())
+    );
+    assert_eq!(
+        indenta(&AgoType::String(String::new()), &prefix),
+        AgoType::String(String::new())
+    );
+}
+
+#[test]
+fn test_romanum_round_trip() {
+    let cases = [
+        (1, "I"),
+        (4, "IV"),
+        (9, "IX"),
+        (40, "XL"),
+        (90, "XC"),
+        (1994, "MCMXCIV"),
+        (3999, "MMMCMXCIX"),
+    ];
+    for (n, numeral) in cases {
+        let roman = AgoType::String(numeral.to_string());
+        assert_eq!(ad_romanum(&AgoType::Int(n)), roman);
+        assert_eq!(ex_romanum(&roman), AgoType::Int(n));
+    }
+}
+
+#[test]
+#[should_panic(expected = "ad_romanum: 4000 is outside the range 1 to 3999")]
+fn test_ad_romanum_out_of_range() {
+    ad_romanum(&AgoType::Int(4000));
+}
+
+#[test]
+#[should_panic(expected = "ad_romanum: 0 is outside the range")]
+fn test_ad_romanum_zero() {
+    ad_romanum(&AgoType::Int(0));
+}
+
+#[test]
+#[should_panic(expected = "'IIII' is not a valid Roman numeral")]
+fn test_ex_romanum_malformed() {
+    ex_romanum(&AgoType::String("IIII".to_string()));
+}
+
+#[test]
+fn test_filtra_claves() {
+    let key_is_a: AgoLambda =
+        Rc::new(|args: &[AgoType]| AgoType::Bool(args[0] == AgoType::String("a".to_string())));
+    let mut only_a = HashMap::new();
+    only_a.insert("a".to_string(), AgoType::Int(1));
+    assert_eq!(
+        filtra_claves(&sample_struct(), &key_is_a),
+        AgoType::Struct(only_a)
+    );
+
+    let value_is_string: AgoLambda =
+        Rc::new(|args: &[AgoType]| AgoType::Bool(matches!(args[1], AgoType::String(_))));
+    let mut only_b = HashMap::new();
+    only_b.insert("b".to_string(), AgoType::String("hello".to_string()));
+    assert_eq!(
+        filtra_claves(&sample_struct(), &value_is_string),
+        AgoType::Struct(only_b)
+    );
+}
+
+#[test]
+#[should_panic(expected = "filtra_claves predicate must return a Bool")]
+fn test_filtra_claves_non_bool_predicate() {
+    filtra_claves(&sample_struct(), &identity());
+}
+
+#[test]
+fn test_transforma_valores() {
+    let mut prices = HashMap::new();
+    prices.insert("panis".to_string(), AgoType::Int(2));
+    prices.insert("vinum".to_string(), AgoType::Float(4.5));
+    let double: AgoLambda = Rc::new(|args: &[AgoType]| multiply(&args[0], &AgoType::Int(2)));
+
+    let mut doubled = HashMap::new();
+    doubled.insert("panis".to_string(), AgoType::Int(4));
+    doubled.insert("vinum".to_string(), AgoType::Float(9.0));
+    assert_eq!(
+        transforma_valores(&AgoType::Struct(prices), &double),
+        AgoType::Struct(doubled)
+    );
+
+    let stringify: AgoLambda = Rc::new(|args: &[AgoType]| args[0].as_type(TargetType::String));
+    let mut strings = HashMap::new();
+    strings.insert("a".to_string(), AgoType::String("1".to_string()));
+    strings.insert("b".to_string(), AgoType::String("hello".to_string()));
+    assert_eq!(
+        transforma_valores(&sample_struct(), &stringify),
+        AgoType::Struct(strings)
+    );
+}
+
+#[test]
+fn test_applica() {
+    let adder: AgoLambda = Rc::new(|args: &[AgoType]| add(&args[0], &args[1]));
+    assert_eq!(
+        applica(
+            &adder,
+            &AgoType::ListAny(vec![AgoType::Int(2), AgoType::Int(3)])
+        ),
+        AgoType::Int(5)
+    );
+    assert_eq!(
+        applica(&adder, &AgoType::IntList(vec![4, 5])),
+        AgoType::Int(9)
+    );
+
+    let constant: AgoLambda = Rc::new(|args: &[AgoType]| AgoType::Int(args.len() as i128 + 42));
+    assert_eq!(
+        applica(&constant, &AgoType::ListAny(vec![])),
+        AgoType::Int(42)
+    );
+}
+
+#[test]
+#[should_panic(expected = "applica expects a list of arguments")]
+fn test_applica_non_list() {
+    applica(&identity(), &AgoType::Int(1));
+}
+
+#[test]
+fn test_applica_partim() {
+    let minus: AgoLambda = Rc::new(|args: &[AgoType]| subtract(&args[0], &args[1]));
+    let ten_minus = applica_partim(minus, vec![AgoType::Int(10)]);
+    assert_eq!(ten_minus(&[AgoType::Int(3)]), AgoType::Int(7));
+    assert_eq!(ten_minus(&[AgoType::Int(12)]), AgoType::Int(-2));
+}
+
+#[test]
+fn test_memoiza() {
+    let calls = Rc::new(std::cell::Cell::new(0));
+    let counter = Rc::clone(&calls);
+    let square: AgoLambda = Rc::new(move |args: &[AgoType]| {
+        counter.set(counter.get() + 1);
+        multiply(&args[0], &args[0])
+    });
+    let memo = memoiza(square);
+
+    assert_eq!(memo(&[AgoType::Int(4)]), AgoType::Int(16));
+    assert_eq!(memo(&[AgoType::Int(4)]), AgoType::Int(16));
+    assert_eq!(calls.get(), 1);
+    assert_eq!(memo(&[AgoType::Int(5)]), AgoType::Int(25));
+    assert_eq!(calls.get(), 2);
+
+    // Struct arguments hit the cache regardless of key order.
+    let ignore: AgoLambda = Rc::new({
+        let counter = Rc::clone(&calls);
+        move |_: &[AgoType]| {
+            counter.set(counter.get() + 1);
+            AgoType::Null
+        }
+    });
+    let memo_struct = memoiza(ignore);
+    memo_struct(&[sample_struct()]);
+    memo_struct(&[sample_struct()]);
+    assert_eq!(calls.get(), 3);
+}
+
+#[test]
+fn test_compositio() {
+    let increment: AgoLambda = Rc::new(|args: &[AgoType]| add(&args[0], &AgoType::Int(1)));
+    let double: AgoLambda = Rc::new(|args: &[AgoType]| multiply(&args[0], &AgoType::Int(2)));
+
+    let double_then_increment = compositio(Rc::clone(&increment), Rc::clone(&double));
+    assert_eq!(double_then_increment(&[AgoType::Int(5)]), AgoType::Int(11));
+
+    let increment_then_double = compositio(double, increment);
+    assert_eq!(increment_then_double(&[AgoType::Int(5)]), AgoType::Int(12));
+}
+
+#[test]
+#[should_panic(expected = "Cannot perform numeric operation")]
+fn test_compositio_propagates_panic() {
+    let negate: AgoLambda = Rc::new(|args: &[AgoType]| unary_minus(&args[0]));
+    let to_string: AgoLambda = Rc::new(|args: &[AgoType]| args[0].as_type(TargetType::String));
+    let broken = compositio(
+        Rc::new(|args: &[AgoType]| multiply(&args[0], &AgoType::Int(2))),
+        compositio(to_string, negate),
+    );
+    broken(&[AgoType::Int(1)]);
+}
+
+#[test]
+fn test_itera_dum() {
+    let double: AgoLambda = Rc::new(|args: &[AgoType]| multiply(&args[0], &AgoType::Int(2)));
+    let at_most_100: AgoLambda =
+        Rc::new(|args: &[AgoType]| less_equal(&args[0], &AgoType::Int(100)));
+    assert_eq!(
+        itera_dum(&AgoType::Int(1), &double, &at_most_100).as_type(TargetType::IntList),
+        AgoType::IntList(vec![1, 2, 4, 8, 16, 32, 64])
+    );
+    assert_eq!(
+        itera_dum(&AgoType::Int(200), &double, &at_most_100),
+        AgoType::ListAny(vec![])
+    );
+}
+
+#[test]
+#[should_panic(expected = "itera_dum exceeded 1000000 iterations")]
+fn test_itera_dum_cap() {
+    let always: AgoLambda = Rc::new(|_: &[AgoType]| AgoType::Bool(true));
+    itera_dum(&AgoType::Null, &identity(), &always);
+}
+
+#[test]
+fn test_frequentia() {
+    let words = AgoType::StringList(
+        ["ave", "vale", "ave", "ave"]
+            .iter()
+            .map(|w| w.to_string())
+            .collect(),
+    );
+    let mut expected = HashMap::new();
+    expected.insert("ave".to_string(), AgoType::Int(3));
+    expected.insert("vale".to_string(), AgoType::Int(1));
+    assert_eq!(frequentia(&words), AgoType::Struct(expected));
+
+    let mut expected = HashMap::new();
+    expected.insert("1".to_string(), AgoType::Int(2));
+    expected.insert("-2".to_string(), AgoType::Int(1));
+    assert_eq!(
+        frequentia(&AgoType::IntList(vec![1, -2, 1])),
+        AgoType::Struct(expected)
+    );
+    assert_eq!(
+        frequentia(&AgoType::BoolList(vec![])),
+        AgoType::Struct(HashMap::new())
+    );
+
+    // Equal Structs built separately (each with its own HashMap seed) share
+    // one bucket.
+    let point = || {
+        let mut map = HashMap::new();
+        for (i, key) in ["x", "y", "z", "w", "v"].iter().enumerate() {
+            map.insert(key.to_string(), AgoType::Int(i as i128));
+        }
+        AgoType::Struct(map)
+    };
+    let counts = frequentia(&AgoType::ListAny((0..10).map(|_| point()).collect()));
+    let AgoType::Struct(counts) = counts else {
+        panic!("frequentia must return a Struct");
+    };
+    assert_eq!(counts.len(), 1);
+    assert_eq!(counts.values().next(), Some(&AgoType::Int(10)));
+}
+
+#[test]
+fn test_struct_as_type_string_is_sorted() {
+    let mut map = HashMap::new();
+    map.insert("b".to_string(), AgoType::Int(2));
+    map.insert("a".to_string(), AgoType::Int(1));
+    map.insert("c".to_string(), AgoType::Int(3));
+    assert_eq!(
+        AgoType::Struct(map).as_type(TargetType::String),
+        AgoType::String("{ a: 1, b: 2, c: 3 }".to_string())
+    );
+}
+
+#[test]
+fn test_rotatio() {
+    let list = AgoType::IntList(vec![1, 2, 3, 4]);
+    assert_eq!(
+        rotatio(&list, &AgoType::Int(1)),
+        AgoType::IntList(vec![2, 3, 4, 1])
+    );
+    assert_eq!(
+        rotatio(&list, &AgoType::Int(-1)),
+        AgoType::IntList(vec![4, 1, 2, 3])
+    );
+    assert_eq!(
+        rotatio(&list, &AgoType::Int(9)),
+        AgoType::IntList(vec![2, 3, 4, 1])
+    );
+    assert_eq!(
+        rotatio(&AgoType::StringList(vec![]), &AgoType::Int(3)),
+        AgoType::StringList(vec![])
+    );
+    assert_eq!(
+        rotatio(&sample_any_list(), &AgoType::Int(2)),
+        AgoType::ListAny(vec![
+            AgoType::Bool(false),
+            AgoType::Int(1),
+            AgoType::String("two".to_string()),
+        ])
+    );
+}
+
+#[test]
+fn test_intercala() {
+    assert_eq!(
+        intercala(&AgoType::IntList(vec![1, 3]), &AgoType::IntList(vec![2, 4])),
+        AgoType::IntList(vec![1, 2, 3, 4])
+    );
+    assert_eq!(
+        intercala(&AgoType::IntList(vec![1]), &AgoType::IntList(vec![2, 4, 6])),
+        AgoType::IntList(vec![1, 2, 4, 6])
+    );
+    assert_eq!(
+        intercala(
+            &AgoType::IntList(vec![1, 2]),
+            &AgoType::StringList(vec!["a".to_string()])
+        ),
+        AgoType::ListAny(vec![
+            AgoType::Int(1),
+            AgoType::String("a".to_string()),
+            AgoType::Int(2),
+        ])
+    );
+}
+
+#[test]
+#[should_panic(expected = "Cannot call 'intercala'")]
+fn test_intercala_non_list() {
+    intercala(&AgoType::IntList(vec![1]), &AgoType::Int(2));
+}
+
+#[test]
+fn test_transpone() {
+    let matrix = AgoType::ListAny(vec![
+        AgoType::IntList(vec![1, 2, 3]),
+        AgoType::IntList(vec![4, 5, 6]),
+    ]);
+    assert_eq!(
+        transpone(&matrix),
+        AgoType::ListAny(vec![
+            AgoType::IntList(vec![1, 4]),
+            AgoType::IntList(vec![2, 5]),
+            AgoType::IntList(vec![3, 6]),
+        ])
+    );
+    assert_eq!(
+        transpone(&AgoType::ListAny(vec![])),
+        AgoType::ListAny(vec![])
+    );
+
+    let mixed = AgoType::ListAny(vec![
+        AgoType::IntList(vec![1]),
+        AgoType::StringList(vec!["a".to_string()]),
+    ]);
+    assert_eq!(
+        transpone(&mixed),
+        AgoType::ListAny(vec![AgoType::ListAny(vec![
+            AgoType::Int(1),
+            AgoType::String("a".to_string()),
+        ])])
+    );
+}
+
+#[test]
+#[should_panic(expected = "transpone needs rows of equal length, but row 1 has 1 elements")]
+fn test_transpone_ragged() {
+    transpone(&AgoType::ListAny(vec![
+        AgoType::IntList(vec![1, 2]),
+        AgoType::IntList(vec![3]),
+    ]));
+}
+
+#[test]
+fn test_plica_explica() {
+    let runs = AgoType::IntList(vec![7, 7, 7, 1, 2, 2]);
+    let encoded = plica(&runs);
+    assert_eq!(
+        encoded,
+        AgoType::ListAny(vec![
+            AgoType::ListAny(vec![AgoType::Int(7), AgoType::Int(3)]),
+            AgoType::ListAny(vec![AgoType::Int(1), AgoType::Int(1)]),
+            AgoType::ListAny(vec![AgoType::Int(2), AgoType::Int(2)]),
+        ])
+    );
+    assert_eq!(explica(&encoded, TargetType::IntList), runs);
+
+    let distinct = AgoType::StringList(vec!["a".to_string(), "b".to_string(), "c".to_string()]);
+    assert_eq!(explica(&plica(&distinct), distinct.target_type()), distinct);
+    assert_eq!(
+        explica(&plica(&sample_any_list()), TargetType::ListAny),
+        sample_any_list()
+    );
+}
+
+#[test]
+fn test_plica_explica_keeps_variant() {
+    let round_trip = |x: &AgoType| explica(&plica(x), x.target_type());
+
+    let text = AgoType::String("aab".to_string());
+    assert_eq!(round_trip(&text), text);
+    let empty_text = AgoType::String(String::new());
+    assert_eq!(round_trip(&empty_text), empty_text);
+
+    let empty = AgoType::IntList(vec![]);
+    assert_eq!(round_trip(&empty), empty);
+
+    let homogeneous = AgoType::ListAny(vec![AgoType::Int(1), AgoType::Int(1), AgoType::Int(2)]);
+    assert_eq!(round_trip(&homogeneous), homogeneous);
+}
+
+#[test]
+#[should_panic(expected = "explica expects [value, count] pairs")]
+fn test_explica_malformed() {
+    explica(
+        &AgoType::ListAny(vec![AgoType::Int(1)]),
+        TargetType::ListAny,
+    );
+}
+
+#[test]
+#[should_panic(expected = "explica cannot decode into Struct")]
+fn test_explica_bad_target() {
+    explica(&AgoType::ListAny(vec![]), TargetType::Struct);
+}
+
+#[test]
+fn test_cumula() {
+    let sum: AgoLambda = Rc::new(|args: &[AgoType]| add(&args[0], &args[1]));
+    assert_eq!(
+        cumula(&AgoType::IntList(vec![1, 2, 3]), &AgoType::Int(0), &sum),
+        AgoType::ListAny(vec![
+            AgoType::Int(0),
+            AgoType::Int(1),
+            AgoType::Int(3),
+            AgoType::Int(6),
+        ])
+    );
+
+    let words = AgoType::StringList(vec!["a".to_string(), "b".to_string()]);
+    assert_eq!(
+        cumula(&words, &AgoType::String(String::new()), &sum),
+        AgoType::ListAny(vec![
+            AgoType::String(String::new()),
+            AgoType::String("a".to_string()),
+            AgoType::String("ab".to_string()),
+        ])
+    );
+    assert_eq!(
+        cumula(&AgoType::IntList(vec![]), &AgoType::Int(5), &sum),
+        AgoType::ListAny(vec![AgoType::Int(5)])
+    );
+}
+
+#[test]
+fn test_bit_helpers() {
+    assert_eq!(numerus_bitorum(&AgoType::Int(0)), AgoType::Int(0));
+    assert_eq!(numerus_bitorum(&AgoType::Int(64)), AgoType::Int(1));
+    assert_eq!(numerus_bitorum(&AgoType::Int(7)), AgoType::Int(3));
+    assert_eq!(numerus_bitorum(&AgoType::Int(-1)), AgoType::Int(128));
+
+    assert_eq!(longitudo_bitorum(&AgoType::Int(0)), AgoType::Int(0));
+    assert_eq!(longitudo_bitorum(&AgoType::Int(64)), AgoType::Int(7));
+    assert_eq!(longitudo_bitorum(&AgoType::Int(-1)), AgoType::Int(1));
+    assert_eq!(
+        longitudo_bitorum(&AgoType::Int(i128::MIN)),
+        AgoType::Int(128)
+    );
+}
+
+#[test]
+fn test_extrema() {
+    assert_eq!(
+        extrema(&AgoType::IntList(vec![3, -1, 8, 0])),
+        AgoType::ListAny(vec![AgoType::Int(-1), AgoType::Int(8)])
+    );
+    assert_eq!(
+        extrema(&AgoType::StringList(vec![
+            "m".to_string(),
+            "z".to_string(),
+            "a".to_string()
+        ])),
+        AgoType::ListAny(vec![
+            AgoType::String("a".to_string()),
+            AgoType::String("z".to_string()),
+        ])
+    );
+    assert_eq!(
+        extrema(&AgoType::FloatList(vec![2.5])),
+        AgoType::ListAny(vec![AgoType::Float(2.5), AgoType::Float(2.5)])
+    );
+}
+
+#[test]
+#[should_panic(expected = "Cannot reduce an empty list")]
+fn test_extrema_empty() {
+    extrema(&AgoType::IntList(vec![]));
+}
+
+#[test]
+fn test_media_mediana() {
+    let odd = AgoType::IntList(vec![5, 1, 3]);
+    assert_float_near(media(&odd), 3.0);
+    assert_float_near(mediana(&odd), 3.0);
+    assert_eq!(odd, AgoType::IntList(vec![5, 1, 3]));
+
+    let even = AgoType::FloatList(vec![4.0, 1.0, 2.0, 10.0]);
+    assert_float_near(media(&even), 4.25);
+    assert_float_near(mediana(&even), 3.0);
+
+    let single = AgoType::ListAny(vec![AgoType::Int(7)]);
+    assert_float_near(media(&single), 7.0);
+    assert_float_near(mediana(&single), 7.0);
+}
+
+#[test]
+#[should_panic(expected = "mediana of an empty list is undefined")]
+fn test_mediana_empty() {
+    mediana(&AgoType::FloatList(vec![]));
+}
+
+#[test]
+fn test_deviatio() {
+    let data = AgoType::IntList(vec![2, 4, 4, 4, 5, 5, 7, 9]);
+    assert_float_near(deviatio(&data), 2.0);
+    assert_float_near(deviatio_sample(&data), 2.138_089_935_299_395);
+    assert_float_near(deviatio(&AgoType::FloatList(vec![3.5])), 0.0);
+}
+
+#[test]
+#[should_panic(expected = "deviatio of an empty list is undefined")]
+fn test_deviatio_empty() {
+    deviatio(&AgoType::IntList(vec![]));
+}
+
+#[test]
+#[should_panic(expected = "deviatio_sample requires at least two values")]
+fn test_deviatio_sample_single() {
+    deviatio_sample(&AgoType::IntList(vec![1]));
+}
+
+#[test]
+fn test_claves_ordinatae() {
+    assert_eq!(
+        claves_ordinatae(&sample_struct()),
+        AgoType::StringList(vec!["a".to_string(), "b".to_string()])
+    );
+}
+
+#[test]
+#[should_panic(expected = "Cannot call 'claves_ordinatae' on type")]
+fn test_claves_ordinatae_non_struct() {
+    claves_ordinatae(&AgoType::Int(1));
+}
+
+#[test]
+fn test_valores_ordinati() {
+    let mut names = HashMap::new();
+    names.insert("b".to_string(), AgoType::String("beta".to_string()));
+    names.insert("a".to_string(), AgoType::String("alpha".to_string()));
+    assert_eq!(
+        valores_ordinati(&AgoType::Struct(names)),
+        AgoType::StringList(vec!["alpha".to_string(), "beta".to_string()])
+    );
+    assert_eq!(
+        valores_ordinati(&AgoType::Struct(HashMap::new())),
+        AgoType::ListAny(vec![])
+    );
+}
+
+#[test]
+#[should_panic(expected = "key 'b' holds String(\"hello\")")]
+fn test_valores_ordinati_mixed() {
+    valores_ordinati(&sample_struct());
+}
+
+#[test]
+fn test_paria_ordinata() {
+    let mut map = HashMap::new();
+    map.insert("zeta".to_string(), AgoType::Int(3));
+    map.insert("alpha".to_string(), AgoType::Bool(true));
+    map.insert("mu".to_string(), AgoType::String("m".to_string()));
+    let pairs = paria_ordinata(&AgoType::Struct(map));
+    let pair = |k: &str, v: AgoType| AgoType::ListAny(vec![AgoType::String(k.to_string()), v]);
+    assert_eq!(
+        pairs,
+        AgoType::ListAny(vec![
+            pair("alpha", AgoType::Bool(true)),
+            pair("mu", AgoType::String("m".to_string())),
+            pair("zeta", AgoType::Int(3)),
+        ])
+    );
+    assert_eq!(
+        paria_ordinata(&AgoType::Struct(HashMap::new())),
+        AgoType::ListAny(vec![])
+    );
+}
+
+#[test]
+fn test_ex_chorda_basi() {
+    let parse =
+        |s: &str, base: i128| ex_chorda_basi(&AgoType::String(s.to_string()), &AgoType::Int(base));
+    assert_eq!(parse("ff", 16), AgoType::Int(255));
+    assert_eq!(parse("101", 2), AgoType::Int(5));
+    assert_eq!(parse("-z", 36), AgoType::Int(-35));
+}
+
+#[test]
+#[should_panic(expected = "ex_chorda_basi: cannot parse '12' in base 2")]
+fn test_ex_chorda_basi_invalid_digit() {
+    ex_chorda_basi(&AgoType::String("12".to_string()), &AgoType::Int(2));
+}
+
+#[test]
+#[should_panic(expected = "base must be an Int from 2 to 36")]
+fn test_ex_chorda_basi_invalid_base() {
+    ex_chorda_basi(&AgoType::String("10".to_string()), &AgoType::Int(37));
+}
+
+#[test]
+fn test_adnota() {
+    let words = AgoType::StringList(vec!["ab".to_string(), "c".to_string(), "def".to_string()]);
+    let pair =
+        |n: i128, w: &str| AgoType::ListAny(vec![AgoType::Int(n), AgoType::String(w.to_string())]);
+    assert_eq!(
+        adnota(&words, &char_count()),
+        AgoType::ListAny(vec![pair(2, "ab"), pair(1, "c"), pair(3, "def")])
+    );
+}
+
+#[test]
+fn test_ex_json_lineae() {
+    let text =
+        "{\"a\": 1, \"b\": \"hello\"}\n\n  {\"ok\": true, \"x\": [1.5, null, \"q\\\"\"]}  \n{}\n";
+    let mut second = HashMap::new();
+    second.insert("ok".to_string(), AgoType::Bool(true));
+    second.insert(
+        "x".to_string(),
+        AgoType::ListAny(vec![
+            AgoType::Float(1.5),
+            AgoType::Null,
+            AgoType::String("q\"".to_string()),
+        ]),
+    );
+    assert_eq!(
+        ex_json_lineae(&AgoType::String(text.to_string())),
+        AgoType::ListAny(vec![
+            sample_struct(),
+            AgoType::Struct(second),
+            AgoType::Struct(HashMap::new()),
+        ])
+    );
+}
+
+#[test]
+fn test_ex_json_lineae_escapes_and_numbers() {
+    let text = "[\"\\ud83d\\ude00\", \"\\u00e9\", 0, -0.5, 1e3, 12]";
+    assert_eq!(
+        ex_json_lineae(&AgoType::String(text.to_string())),
+        AgoType::ListAny(vec![AgoType::ListAny(vec![
+            AgoType::String("\u{1F600}".to_string()),
+            AgoType::String("\u{e9}".to_string()),
+            AgoType::Int(0),
+            AgoType::Float(-0.5),
+            AgoType::Float(1000.0),
+            AgoType::Int(12),
+        ])])
+    );
+}
+
+#[test]
+#[should_panic(expected = "ex_json_lineae: line 1: invalid number '01'")]
+fn test_ex_json_lineae_leading_zero() {
+    ex_json_lineae(&AgoType::String("[01]".to_string()));
+}
+
+#[test]
+fn test_ex_json_lineae_depth_limit() {
+    let nested = |depth: usize| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+    let mut value = AgoType::ListAny(vec![]);
+    for _ in 1..128 {
+        value = AgoType::ListAny(vec![value]);
+    }
+    assert_eq!(
+        ex_json_lineae(&AgoType::String(nested(128))),
+        AgoType::ListAny(vec![value])
+    );
+}
+
+#[test]
+#[should_panic(expected = "ex_json_lineae: line 1: nesting too deep")]
+fn test_ex_json_lineae_too_deep() {
+    let depth = 10_000;
+    let text = format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+    ex_json_lineae(&AgoType::String(text));
+}
+
+#[test]
+#[should_panic(expected = "unpaired surrogate")]
+fn test_ex_json_lineae_lone_surrogate() {
+    ex_json_lineae(&AgoType::String("\"\\ud83d\"".to_string()));
+}
+
+#[test]
+#[should_panic(expected = "ex_json_lineae: line 2:")]
+fn test_ex_json_lineae_bad_line() {
+    ex_json_lineae(&AgoType::String("{\"a\": 1}\n{\"a\": }\n".to_string()));
+}
+
+#[test]
+fn test_csv_round_trip() {
+    let row = |fields: &[&str]| AgoType::StringList(fields.iter().map(|f| f.to_string()).collect());
+    let rows = AgoType::ListAny(vec![
+        row(&["name", "note"]),
+        row(&["Smith, J", "said \"hi\""]),
+        row(&["multi\nline", ""]),
+    ]);
+    let text = ad_csv(&rows);
+    assert_eq!(
+        text,
+        AgoType::String(
+            "name,note\n\"Smith, J\",\"said \"\"hi\"\"\"\n\"multi\nline\",".to_string()
+        )
+    );
+    assert_eq!(ex_csv(&text), rows);
+
+    let lone_empty = AgoType::ListAny(vec![row(&["a"]), row(&[""])]);
+    let text = ad_csv(&lone_empty);
+    assert_eq!(text, AgoType::String("a\n\"\"".to_string()));
+    assert_eq!(ex_csv(&text), lone_empty);
+
+    assert_eq!(
+        ex_csv(&AgoType::String("a,b\r\nc,d\n".to_string())),
+        AgoType::ListAny(vec![row(&["a", "b"]), row(&["c", "d"])])
+    );
+}
+
+#[test]
+#[should_panic(expected = "ad_csv cannot write empty row 1")]
+fn test_csv_round_trip_empty_row() {
+    // `[]` would be written as a blank line and read back as `[""]`.
+    ad_csv(&AgoType::ListAny(vec![
+        AgoType::StringList(vec!["a".to_string()]),
+        AgoType::StringList(vec![]),
+        AgoType::StringList(vec!["b".to_string()]),
+    ]));
+}
+
+#[test]
+#[should_panic(expected = "ex_csv: unterminated quoted field")]
+fn test_ex_csv_unterminated() {
+    ex_csv(&AgoType::String("a,\"b".to_string()));
+}
+
+#[test]
+fn test_est_inanis() {
+    assert_eq!(est_inanis(&AgoType::Null), AgoType::Bool(true));
+    assert_eq!(est_inanis(&AgoType::ListAny(vec![])), AgoType::Bool(false));
+    assert_eq!(est_inanis(&AgoType::IntList(vec![])), AgoType::Bool(false));
+    assert_eq!(
+        est_inanis(&AgoType::String(String::new())),
+        AgoType::Bool(false)
+    );
+    assert_eq!(est_inanis(&AgoType::Bool(false)), AgoType::Bool(false));
+}
+
+// --- Operator Tests ---
+
+#[test]
+fn test_arithmetic_operators() {
+    // Int, Int
+    assert_eq!(add(&AgoType::Int(5), &AgoType::Int(2)), AgoType::Int(7));
+    assert_eq!(
+        subtract(&AgoType::Int(5), &AgoType::Int(2)),
+        AgoType::Int(3)
+    );
+    assert_eq!(
+        multiply(&AgoType::Int(5), &AgoType::Int(2)),
+        AgoType::Int(10)
+    );
+    assert_eq!(divide(&AgoType::Int(5), &AgoType::Int(2)), AgoType::Int(2));
+    assert_eq!(modulo(&AgoType::Int(5), &AgoType::Int(2)), AgoType::Int(1));
+
+    // Float, Float
+    assert_eq!(
+        add(&AgoType::Float(5.0), &AgoType::Float(2.0)),
+        AgoType::Float(7.0)
+    );
+    assert_eq!(
+        subtract(&AgoType::Float(5.0), &AgoType::Float(2.0)),
+        AgoType::Float(3.0)
+    );
+    assert_eq!(
+        multiply(&AgoType::Float(5.0), &AgoType::Float(2.0)),
+        AgoType::Float(10.0)
+    );
+    assert_eq!(
+        divide(&AgoType::Float(5.0), &AgoType::Float(2.0)),
+        AgoType::Float(2.5)
+    );
+
+    // Mixed
+    assert_eq!(
+        add(&AgoType::Int(5), &AgoType::Float(2.5)),
+        AgoType::Float(7.5)
+    );
+    assert_eq!(
+        subtract(&AgoType::Float(5.0), &AgoType::Int(2)),
+        AgoType::Float(3.0)
+    );
+}
+
+#[test]
+fn test_add_concatenation() {
+    // String
+    let s1 = AgoType::String("hello".to_string());
+    let s2 = AgoType::String(" world".to_string());
+    assert_eq!(add(&s1, &s2), AgoType::String("hello world".to_string()));
+
+    // List
+    let l1 = AgoType::IntList(vec![1, 2]);
+    let l2 = AgoType::IntList(vec![3, 4]);
+    assert_eq!(add(&l1, &l2), AgoType::IntList(vec![1, 2, 3, 4]));
+}
+
+#[test]
+#[should_panic]
+fn test_arithmetic_panic() {
+    add(&AgoType::Int(5), &AgoType::String("hello".to_string()));
+}
+
+#[test]
+fn test_comparison_operators() {
+    // Numeric
+    assert_eq!(
+        greater_than(&AgoType::Int(5), &AgoType::Int(2)),
+        AgoType::Bool(true)
+    );
+    assert_eq!(
+        less_than(&AgoType::Float(5.0), &AgoType::Int(2)),
+        AgoType::Bool(false)
+    );
+    assert_eq!(
+        greater_equal(&AgoType::Int(5), &AgoType::Float(5.0)),
+        AgoType::Bool(true)
+    );
+    assert_eq!(
+        less_equal(&AgoType::Int(5), &AgoType::Int(5)),
+        AgoType::Bool(true)
+    );
+
+    // String
+    assert_eq!(
+        greater_than(
+            &AgoType::String("b".to_string()),
+            &AgoType::String("a".to_string())
+        ),
+        AgoType::Bool(true)
+    );
+    assert_eq!(
+        less_than(
+            &AgoType::String("b".to_string()),
+            &AgoType::String("a".to_string())
+        ),
+        AgoType::Bool(false)
+    );
+}
+
+#[test]
+fn test_comparison_bool_and_null() {
+    let f = AgoType::Bool(false);
+    let t = AgoType::Bool(true);
+    assert_eq!(less_than(&f, &t), AgoType::Bool(true));
+    assert_eq!(greater_than(&f, &t), AgoType::Bool(false));
+    assert_eq!(less_equal(&t, &t), AgoType::Bool(true));
+
+    assert_eq!(
+        less_than(&AgoType::Null, &AgoType::Int(0)),
+        AgoType::Bool(true)
+    );
+    assert_eq!(
+        greater_than(&AgoType::String(String::new()), &AgoType::Null),
+        AgoType::Bool(true)
+    );
+    assert_eq!(
+        less_equal(&AgoType::Null, &AgoType::Null),
+        AgoType::Bool(true)
+    );
+    assert_eq!(
+        less_than(&AgoType::Null, &AgoType::Null),
+        AgoType::Bool(false)
+    );
+
+    let mut list = AgoType::ListAny(vec![AgoType::Int(2), AgoType::Null, AgoType::Int(1)]);
+    ordina_per(&mut list, &identity());
+    assert_eq!(
+        list,
+        AgoType::ListAny(vec![AgoType::Null, AgoType::Int(1), AgoType::Int(2)])
+    );
+}
+
+#[test]
+fn test_comparison_nan_matches_compare() {
+    let nan = AgoType::Float(f64::NAN);
+    let one = AgoType::Int(1);
+    assert_eq!(compare(&nan, &one), None);
+    assert_eq!(less_than(&nan, &one), AgoType::Bool(false));
+    assert_eq!(greater_equal(&nan, &one), AgoType::Bool(false));
+    assert_eq!(less_equal(&one, &nan), AgoType::Bool(false));
+    assert_eq!(greater_than(&nan, &nan), AgoType::Bool(false));
+}
+
+#[test]
+#[should_panic(expected = "Cannot perform comparison")]
+fn test_comparison_bool_int() {
+    less_than(&AgoType::Bool(true), &AgoType::Int(1));
+}
+
+#[test]
+fn test_logical_operators() {
+    assert_eq!(
+        and(&AgoType::Bool(true), &AgoType::Bool(false)),
+        AgoType::Bool(false)
+    );
+    assert_eq!(
+        and(&AgoType::Bool(true), &AgoType::Bool(true)),
+        AgoType::Bool(true)
+    );
+    assert_eq!(
+        or(&AgoType::Bool(true), &AgoType::Bool(false)),
+        AgoType::Bool(true)
+    );
+    assert_eq!(
+        or(&AgoType::Bool(false), &AgoType::Bool(false)),
+        AgoType::Bool(false)
+    );
+    assert_eq!(not(&AgoType::Bool(true)), AgoType::Bool(false));
+    assert_eq!(not(&AgoType::Bool(false)), AgoType::Bool(true));
+}
+
+#[test]
+fn test_aut() {
+    let t = AgoType::Bool(true);
+    let f = AgoType::Bool(false);
+    assert_eq!(aut(&t, &t), AgoType::Bool(false));
+    assert_eq!(aut(&t, &f), AgoType::Bool(true));
+    assert_eq!(aut(&f, &t), AgoType::Bool(true));
+    assert_eq!(aut(&f, &f), AgoType::Bool(false));
+}
+
+#[test]
+#[should_panic(expected = "Cannot perform logical 'xor'")]
+fn test_aut_non_bool() {
+    aut(&AgoType::Int(1), &AgoType::Bool(true));
+}
+
+#[test]
+fn test_implicat() {
+    let t = AgoType::Bool(true);
+    let f = AgoType::Bool(false);
+    assert_eq!(implicat(&t, &t), AgoType::Bool(true));
+    assert_eq!(implicat(&t, &f), AgoType::Bool(false));
+    assert_eq!(implicat(&f, &t), AgoType::Bool(true));
+    assert_eq!(implicat(&f, &f), AgoType::Bool(true));
+}
+
+#[test]
+#[should_panic(expected = "Cannot perform logical implication")]
+fn test_implicat_non_bool() {
+    implicat(&AgoType::Bool(false), &AgoType::Null);
+}
+
+#[test]
+#[should_panic]
+fn test_logical_panic() {
+    and(&AgoType::Bool(true), &AgoType::Int(1));
+}
+
+#[test]
+fn test_bitwise_operators() {
+    assert_eq!(
+        bitwise_and(&AgoType::Int(6), &AgoType::Int(3)),
+        AgoType::Int(2)
+    ); // 110 & 011 = 010
+    assert_eq!(
+        bitwise_or(&AgoType::Int(6), &AgoType::Int(3)),
+        AgoType::Int(7)
+    ); // 110 | 011 = 111
+    assert_eq!(
+        bitwise_xor(&AgoType::Int(6), &AgoType::Int(3)),
+        AgoType::Int(5)
+    ); // 110 ^ 011 = 101
+}
+
+#[test]
+#[should_panic]
+fn test_bitwise_panic() {
+    bitwise_and(&AgoType::Int(6), &AgoType::Float(3.0));
+}
+
+#[test]
+fn test_unary_operators() {
+    assert_eq!(unary_minus(&AgoType::Int(5)), AgoType::Int(-5));
+    assert_eq!(unary_minus(&AgoType::Float(5.0)), AgoType::Float(-5.0));
+    assert_eq!(unary_plus(&AgoType::Int(5)), AgoType::Int(5));
+}
+
+#[test]
+fn test_contains() {
+    // In String
+    assert_eq!(
+        contains(
+            &AgoType::String("hello".to_string()),
+            &AgoType::String("ell".to_string())
+        ),
+        AgoType::Bool(true)
+    );
+    assert_eq!(
+        contains(
+            &AgoType::String("hello".to_string()),
+            &AgoType::String("z".to_string())
+        ),
+        AgoType::Bool(false)
+    );
+
+    // In Struct (key)
+    assert_eq!(
+        contains(&sample_struct(), &AgoType::String("a".to_string())),
+        AgoType::Bool(true)
+    );
+    assert_eq!(
+        contains(&sample_struct(), &AgoType::String("z".to_string())),
+        AgoType::Bool(false)
+    );
+
+    // In List
+    assert_eq!(
+        contains(&AgoType::IntList(vec![1, 2, 3]), &AgoType::Int(2)),
+        AgoType::Bool(true)
+    );
+    assert_eq!(
+        contains(&AgoType::IntList(vec![1, 2, 3]), &AgoType::Int(4)),
+        AgoType::Bool(false)
+    );
+    assert_eq!(
+        contains(&sample_any_list(), &AgoType::String("two".to_string())),
+        AgoType::Bool(true)
+    );
+}
+
+#[test]
+fn test_contains_null() {
+    assert_eq!(
+        contains(&AgoType::IntList(vec![1]), &AgoType::Null),
+        AgoType::Bool(false)
+    );
+    assert_eq!(
+        contains(&AgoType::StringList(vec![]), &AgoType::Null),
+        AgoType::Bool(false)
+    );
+    assert_eq!(
+        contains(&AgoType::ListAny(vec![AgoType::Null]), &AgoType::Null),
+        AgoType::Bool(true)
+    );
+    assert_eq!(
+        contains(&sample_any_list(), &AgoType::Null),
+        AgoType::Bool(false)
+    );
+}
+
+#[test]
+fn test_contains_nan() {
+    let floats = AgoType::FloatList(vec![1.0, f64::NAN]);
+    assert_eq!(
+        contains(&floats, &AgoType::Float(f64::NAN)),
+        AgoType::Bool(false)
+    );
+    assert_eq!(continet_nan(&floats), AgoType::Bool(true));
+    assert_eq!(
+        continet_nan(&AgoType::FloatList(vec![1.0, 2.0])),
+        AgoType::Bool(false)
+    );
+    assert_eq!(
+        continet_nan(&AgoType::ListAny(vec![
+            AgoType::Int(1),
+            AgoType::Float(f64::NAN)
+        ])),
+        AgoType::Bool(true)
+    );
+}
+
+#[test]
+fn test_continet_structuram() {
+    let mut part = HashMap::new();
+    part.insert("a".to_string(), AgoType::Int(1));
+    assert_eq!(
+        continet_structuram(&sample_struct(), &AgoType::Struct(part)),
+        AgoType::Bool(true)
+    );
+    assert_eq!(
+        continet_structuram(&sample_struct(), &sample_struct()),
+        AgoType::Bool(true)
+    );
+    assert_eq!(
+        continet_structuram(&sample_struct(), &AgoType::Struct(HashMap::new())),
+        AgoType::Bool(true)
+    );
+
+    let mut differing = HashMap::new();
+    differing.insert("a".to_string(), AgoType::Int(2));
+    assert_eq!(
+        continet_structuram(&sample_struct(), &AgoType::Struct(differing)),
+        AgoType::Bool(false)
+    );
+
+    let mut extra = HashMap::new();
+    extra.insert("c".to_string(), AgoType::Null);
+    assert_eq!(
+        continet_structuram(&sample_struct(), &AgoType::Struct(extra)),
+        AgoType::Bool(false)
+    );
+}
+
+#[test]
+#[should_panic(expected = "continet_structuram expects two Structs")]
+fn test_continet_structuram_non_struct() {
+    continet_structuram(&sample_struct(), &AgoType::Null);
+}
+
+#[test]
+fn test_continet_neglecto_casu() {
+    let text = AgoType::String("Senatus PopulusQue Romanus".to_string());
+    assert_eq!(
+        continet_neglecto_casu(&text, &AgoType::String("populusque".to_string())),
+        AgoType::Bool(true)
+    );
+    assert_eq!(
+        continet_neglecto_casu(
+            &AgoType::String("ΣΟΦΙΑ".to_string()),
+            &AgoType::String("σοφ".to_string())
+        ),
+        AgoType::Bool(true)
+    );
+    assert_eq!(
+        continet_neglecto_casu(&text, &AgoType::String("carthago".to_string())),
+        AgoType::Bool(false)
+    );
+    // The strict operator is unchanged
+    assert_eq!(
+        contains(&text, &AgoType::String("populusque".to_string())),
+        AgoType::Bool(false)
+    );
+}
+
+#[test]
+fn test_elvis() {
+    let val = AgoType::Int(10);
+    let default = AgoType::Int(20);
+    let null = AgoType::Null;
 
     // Returns left if not null
     assert_eq!(elvis(&val, &default), val);
@@ -663,6 +3278,29 @@ fn test_elvis_panic() {
     elvis(&AgoType::Null, &AgoType::Null);
 }
 
+#[test]
+fn test_vel_inanis() {
+    let val = AgoType::Int(10);
+    let default = AgoType::Int(20);
+    let null = AgoType::Null;
+
+    assert_eq!(vel_inanis(&val, &default), val);
+    assert_eq!(vel_inanis(&val, &null), val);
+    assert_eq!(vel_inanis(&null, &default), default);
+    assert_eq!(vel_inanis(&null, &null), AgoType::Null);
+}
+
+#[test]
+fn test_inter() {
+    let yes = AgoType::String("yes".to_string());
+    let no = AgoType::String("no".to_string());
+    assert_eq!(inter(&AgoType::Bool(true), &yes, &no), yes);
+    assert_eq!(inter(&AgoType::Bool(false), &yes, &no), no);
+    assert_eq!(inter(&AgoType::IntList(vec![0]), &yes, &no), yes);
+    assert_eq!(inter(&AgoType::IntList(vec![]), &yes, &no), no);
+    assert_eq!(inter(&AgoType::Null, &yes, &no), no);
+}
+
 #[test]
 fn test_slice_operator_creation() {
     let range = slice(&AgoType::Int(1), &AgoType::Int(5));
@@ -721,6 +3359,54 @@ fn test_range_operator_panic_non_int_sliceto() {
     sliceto(&AgoType::Int(1), &AgoType::String("5".to_string()));
 }
 
+#[test]
+fn test_target_type() {
+    let values = vec![
+        (AgoType::Int(1), TargetType::Int),
+        (AgoType::Float(1.5), TargetType::Float),
+        (AgoType::Bool(true), TargetType::Bool),
+        (AgoType::String("s".to_string()), TargetType::String),
+        (AgoType::IntList(vec![1]), TargetType::IntList),
+        (AgoType::FloatList(vec![1.5]), TargetType::FloatList),
+        (AgoType::BoolList(vec![true]), TargetType::BoolList),
+        (
+            AgoType::StringList(vec!["s".to_string()]),
+            TargetType::StringList,
+        ),
+        (sample_struct(), TargetType::Struct),
+        (sample_any_list(), TargetType::ListAny),
+        (
+            AgoType::Range(AgoRange {
+                start: 0,
+                end: 3,
+                inclusive: false,
+            }),
+            TargetType::Range,
+        ),
+        (AgoType::Null, TargetType::Null),
+    ];
+    for (value, expected) in values {
+        assert_eq!(value.target_type(), expected);
+        assert_eq!(value.as_type(value.target_type()), value);
+    }
+}
+
+#[test]
+fn test_list_as_type_to_string_separator() {
+    assert_eq!(
+        AgoType::IntList(vec![1, 2]).as_type(TargetType::String),
+        AgoType::String("1\n2".to_string())
+    );
+    assert_eq!(
+        AgoType::ListAny(vec![AgoType::Int(1), AgoType::Int(2)]).as_type(TargetType::String),
+        AgoType::String("1\n2".to_string())
+    );
+    assert_eq!(
+        sample_any_list().as_type(TargetType::String),
+        AgoType::String("1\ntwo\nfalse".to_string())
+    );
+}
+
 #[test]
 fn test_range_as_type_to_string() {
     let inclusive_range = AgoType::Range(AgoRange {
@@ -895,6 +3581,119 @@ fn test_range_as_type_to_intlist() {
     );
 }
 
+#[test]
+fn test_range_as_type_to_float_and_string_lists() {
+    let inclusive_range = AgoType::Range(AgoRange {
+        start: 1,
+        end: 3,
+        inclusive: true,
+    });
+    assert_eq!(
+        inclusive_range.as_type(TargetType::FloatList),
+        AgoType::FloatList(vec![1.0, 2.0, 3.0])
+    );
+    assert_eq!(
+        inclusive_range.as_type(TargetType::StringList),
+        AgoType::StringList(vec!["1".to_string(), "2".to_string(), "3".to_string()])
+    );
+
+    let exclusive_range = AgoType::Range(AgoRange {
+        start: 1,
+        end: 3,
+        inclusive: false,
+    });
+    assert_eq!(
+        exclusive_range.as_type(TargetType::FloatList),
+        AgoType::FloatList(vec![1.0, 2.0])
+    );
+
+    let empty_range = AgoType::Range(AgoRange {
+        start: 3,
+        end: 3,
+        inclusive: false,
+    });
+    assert_eq!(
+        empty_range.as_type(TargetType::StringList),
+        AgoType::StringList(vec![])
+    );
+}
+
+#[test]
+fn test_range_as_type_to_struct() {
+    let range = AgoType::Range(AgoRange {
+        start: 10,
+        end: 12,
+        inclusive: true,
+    });
+    let mut expected = HashMap::new();
+    expected.insert("0".to_string(), AgoType::Int(10));
+    expected.insert("1".to_string(), AgoType::Int(11));
+    expected.insert("2".to_string(), AgoType::Int(12));
+    assert_eq!(range.as_type(TargetType::Struct), AgoType::Struct(expected));
+
+    let empty_range = AgoType::Range(AgoRange {
+        start: 12,
+        end: 10,
+        inclusive: true,
+    });
+    assert_eq!(
+        empty_range.as_type(TargetType::Struct),
+        AgoType::Struct(HashMap::new())
+    );
+}
+
+#[test]
+fn test_struct_as_type_to_value_lists() {
+    let mut scores = HashMap::new();
+    scores.insert("c".to_string(), AgoType::Int(3));
+    scores.insert("a".to_string(), AgoType::Int(1));
+    scores.insert("b".to_string(), AgoType::Int(2));
+    assert_eq!(
+        AgoType::Struct(scores).as_type(TargetType::IntList),
+        AgoType::IntList(vec![1, 2, 3])
+    );
+
+    let mut weights = HashMap::new();
+    weights.insert("y".to_string(), AgoType::Float(0.5));
+    weights.insert("x".to_string(), AgoType::Float(1.5));
+    assert_eq!(
+        AgoType::Struct(weights).as_type(TargetType::FloatList),
+        AgoType::FloatList(vec![1.5, 0.5])
+    );
+
+    assert_eq!(
+        AgoType::Struct(HashMap::new()).as_type(TargetType::IntList),
+        AgoType::IntList(vec![])
+    );
+}
+
+#[test]
+#[should_panic(expected = "Cannot cast Struct to IntList: key 'b' holds String")]
+fn test_struct_as_type_to_intlist_wrong_value() {
+    sample_struct().as_type(TargetType::IntList);
+}
+
+#[test]
+fn test_scalar_as_type_to_single_element_list() {
+    assert_eq!(
+        AgoType::Int(5).as_type(TargetType::IntList),
+        AgoType::IntList(vec![5])
+    );
+    assert_eq!(
+        AgoType::Float(2.5).as_type(TargetType::FloatList),
+        AgoType::FloatList(vec![2.5])
+    );
+    assert_eq!(
+        AgoType::Bool(false).as_type(TargetType::BoolList),
+        AgoType::BoolList(vec![false])
+    );
+    // Strings split into characters instead of wrapping.
+    assert_eq!(
+        AgoType::String("ab".to_string()).as_type(TargetType::StringList),
+        AgoType::StringList(vec!["a".to_string(), "b".to_string()])
+    );
+}
+
 #[test]
 fn test_list_as_type_to_range() {
     // Non-empty list